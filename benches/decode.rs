@@ -16,6 +16,17 @@ macro_rules! group_decode {
         group.bench_function("decode_bs58", |b| {
             b.iter(|| bs58::decode($encoded).into_vec().unwrap())
         });
+        group.bench_function("decode_bs58_ripple", |b| {
+            let encoded = bs58::encode($decoded)
+                .with_alphabet(bs58::Alphabet::RIPPLE)
+                .into_string();
+            b.iter(|| {
+                bs58::decode(&encoded)
+                    .with_alphabet(bs58::Alphabet::RIPPLE)
+                    .into_vec()
+                    .unwrap()
+            })
+        });
         group.bench_function("decode_bs58_noalloc_slice", |b| {
             let mut output = [0; $decoded_length];
             b.iter(|| bs58::decode($encoded).onto(&mut output[..]).unwrap());
@@ -24,6 +35,16 @@ macro_rules! group_decode {
             let mut output = [0; $decoded_length];
             b.iter(|| bs58::decode($encoded).onto(&mut output).unwrap());
         });
+        group.bench_function("decode_bs58_static_alphabet", |b| {
+            let mut output = [0; $decoded_length];
+            b.iter(|| {
+                bs58::decode::decode_static_into::<bs58::alphabet::Bitcoin>(
+                    $encoded.as_bytes(),
+                    &mut output,
+                )
+                .unwrap()
+            });
+        });
         group.finish();
     }};
 }
@@ -40,6 +61,17 @@ macro_rules! group_decode_long {
         group.bench_function("decode_bs58", |b| {
             b.iter(|| bs58::decode($encoded).into_vec().unwrap())
         });
+        group.bench_function("decode_bs58_ripple", |b| {
+            let encoded = bs58::encode($decoded)
+                .with_alphabet(bs58::Alphabet::RIPPLE)
+                .into_string();
+            b.iter(|| {
+                bs58::decode(&encoded)
+                    .with_alphabet(bs58::Alphabet::RIPPLE)
+                    .into_vec()
+                    .unwrap()
+            })
+        });
         group.bench_function("decode_bs58_noalloc_slice", |b| {
             let mut output = [0; $decoded_length];
             b.iter(|| bs58::decode($encoded).onto(&mut output[..]).unwrap());
@@ -97,5 +129,160 @@ fn bench_decode(c: &mut Criterion) {
     );
 }
 
-criterion_group!(benches, bench_decode);
+/// Big-endian bytes of `58^len - 1`, the value of `len` maximum digits, calculated independently
+/// of the decoder by repeated multiplication.
+fn max_value(len: usize) -> Vec<u8> {
+    let mut value = vec![1u8];
+    for _ in 0..len {
+        let mut carry = 0;
+        for byte in value.iter_mut().rev() {
+            let product = u32::from(*byte) * 58 + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+        if carry > 0 {
+            value.insert(0, carry as u8);
+        }
+    }
+    for byte in value.iter_mut().rev() {
+        let (difference, borrow) = byte.overflowing_sub(1);
+        *byte = difference;
+        if !borrow {
+            break;
+        }
+    }
+    if value[0] == 0 {
+        value.remove(0);
+    }
+    value
+}
+
+fn bench_decode_lengths(c: &mut Criterion) {
+    // Maximal values at lengths around where the output crosses 8, 16 and 32 bytes, and larger
+    // ones where carries propagate furthest, checking the results before measuring
+    let mut group = c.benchmark_group("lengths");
+    for len in [10, 11, 21, 22, 43, 44, 88, 175, 350] {
+        let encoded = "z".repeat(len);
+        let decoded = bs58::decode(&encoded).into_vec().unwrap();
+        assert_eq!(max_value(len), decoded, "{} chars", len);
+        assert_eq!(encoded, bs58::encode(&decoded).into_string());
+        group.bench_function(format!("decode_bs58_{}_chars", len), |b| {
+            b.iter(|| bs58::decode(&encoded).into_vec().unwrap())
+        });
+        group.bench_function(format!("decode_bs58_noalloc_{}_chars", len), |b| {
+            let mut output = [0; 512];
+            b.iter(|| bs58::decode(&encoded).onto(&mut output[..]).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn bench_decode_large(c: &mut Criterion) {
+    // Large inputs, where sizing the output vector matters
+    let mut group = c.benchmark_group("large");
+    for len in [1024, 4096] {
+        let encoded = bs58::encode(vec![0xff; len]).into_string();
+        group.bench_function(format!("decode_bs58_{}_bytes", len), |b| {
+            b.iter(|| bs58::decode(&encoded).into_vec().unwrap())
+        });
+        group.bench_function(format!("decode_bs58_exact_prealloc_{}_bytes", len), |b| {
+            b.iter(|| bs58::decode(&encoded).exact_prealloc().into_vec().unwrap())
+        });
+        group.bench_function(format!("decode_bs58_noalloc_{}_bytes", len), |b| {
+            let mut output = vec![0; len];
+            b.iter(|| bs58::decode(&encoded).onto(&mut output[..]).unwrap());
+        });
+    }
+    group.finish();
+}
+
+fn bench_decode_limbs(c: &mut Criterion) {
+    // The limb decoder against the byte at a time decoder, to choose where to switch between them
+    let mut group = c.benchmark_group("limbs");
+    for len in [1, 2, 3, 4, 5, 10, 32, 64, 128, 256, 318] {
+        let input: Vec<u8> = (0..len).map(|i| (i * 97 + 201) as u8).collect();
+        let encoded = bs58::encode(&input).into_string();
+        let alpha = bs58::Alphabet::BITCOIN;
+        let mut output = vec![0; len];
+        for decode in [
+            bs58::decode::decode_limbs_into,
+            bs58::decode::decode_bytes_into,
+        ] {
+            assert_eq!(Ok(len), decode(encoded.as_bytes(), &mut output, alpha));
+            assert_eq!(input, output);
+        }
+        group.bench_function(format!("decode_limbs_{}_bytes", len), |b| {
+            b.iter(|| bs58::decode::decode_limbs_into(encoded.as_bytes(), &mut output, alpha))
+        });
+        group.bench_function(format!("decode_bytes_{}_bytes", len), |b| {
+            b.iter(|| bs58::decode::decode_bytes_into(encoded.as_bytes(), &mut output, alpha))
+        });
+    }
+    group.finish();
+}
+
+/// A hasher implementing `digest_incremental`, so the payload is hashed while decoding it rather
+/// than in a second pass afterwards like with `DefaultSha256`.
+#[cfg(feature = "check")]
+struct Incremental;
+
+#[cfg(feature = "check")]
+impl bs58::Sha256 for Incremental {
+    fn digest(data: &[&[u8]]) -> [u8; 32] {
+        <bs58::DefaultSha256 as bs58::Sha256>::digest(data)
+    }
+
+    fn digest_incremental(
+        produce: &mut dyn FnMut(&mut bs58::Sha256Update<'_>),
+    ) -> Option<[u8; 32]> {
+        use sha2::Digest;
+
+        let mut hasher = sha2::Sha256::new();
+        produce(&mut |part| hasher.update(part));
+        Some(hasher.finalize().into())
+    }
+}
+
+#[cfg(feature = "check")]
+fn bench_decode_check(c: &mut Criterion) {
+    // Decoding then hashing with the default hasher, against hashing the payload while decoding
+    let mut group = c.benchmark_group("check");
+    for len in [32, 256, 1024, 4096] {
+        let encoded = bs58::encode(vec![0xff; len]).with_check().into_string();
+        let mut output = vec![0; len + 4];
+        group.bench_function(format!("decode_bs58_check_{}_bytes", len), |b| {
+            b.iter(|| {
+                bs58::decode(&encoded)
+                    .with_check(None)
+                    .onto(&mut output[..])
+                    .unwrap()
+            })
+        });
+        group.bench_function(
+            format!("decode_bs58_check_incremental_{}_bytes", len),
+            |b| {
+                b.iter(|| {
+                    bs58::decode(&encoded)
+                        .with_check(None)
+                        .with_sha256::<Incremental>()
+                        .onto(&mut output[..])
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "check"))]
+fn bench_decode_check(_: &mut Criterion) {}
+
+criterion_group!(
+    benches,
+    bench_decode,
+    bench_decode_lengths,
+    bench_decode_large,
+    bench_decode_limbs,
+    bench_decode_check
+);
 criterion_main!(benches);
@@ -16,9 +16,28 @@ macro_rules! group_encode {
         group.bench_function("encode_bs58", |b| {
             b.iter(|| bs58::encode($decoded).into_string())
         });
+        group.bench_function("encode_bs58_ripple", |b| {
+            b.iter(|| {
+                bs58::encode($decoded)
+                    .with_alphabet(bs58::Alphabet::RIPPLE)
+                    .into_string()
+            })
+        });
         group.bench_function("encode_bs58_noalloc", |b| {
             let mut output = String::with_capacity($encoded.len());
-            b.iter(|| bs58::encode($decoded).onto(&mut output));
+            b.iter(|| bs58::encode($decoded).overwrite(&mut output));
+        });
+        group.bench_function("encode_bs58_noalloc_slice", |b| {
+            let mut output = vec![0; $encoded.len()];
+            b.iter(|| bs58::encode($decoded).onto(&mut output[..]).unwrap());
+        });
+        group.bench_function("encode_bs58_static_alphabet", |b| {
+            let input = $decoded;
+            let mut output = vec![0; $encoded.len()];
+            b.iter(|| {
+                bs58::encode::encode_static_into::<bs58::alphabet::Bitcoin>(&input, &mut output)
+                    .unwrap()
+            });
         });
         group.finish();
     }};
@@ -1,8 +1,10 @@
 use anyhow::{anyhow, Context};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::{
-    convert::TryInto,
+    convert::TryFrom,
+    fs::File,
     io::{self, Read, Write},
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
@@ -12,6 +14,7 @@ enum Alphabet {
     Monero,
     Ripple,
     Flickr,
+    Gmp,
     Custom(bs58::Alphabet),
 }
 
@@ -22,6 +25,7 @@ impl Alphabet {
             Alphabet::Monero => bs58::Alphabet::MONERO,
             Alphabet::Ripple => bs58::Alphabet::RIPPLE,
             Alphabet::Flickr => bs58::Alphabet::FLICKR,
+            Alphabet::Gmp => bs58::Alphabet::GMP,
             Alphabet::Custom(custom) => custom,
         }
     }
@@ -36,13 +40,10 @@ impl FromStr for Alphabet {
             "monero" => Alphabet::Monero,
             "ripple" => Alphabet::Ripple,
             "flickr" => Alphabet::Flickr,
+            "gmp" => Alphabet::Gmp,
             custom if custom.starts_with("custom(") && custom.ends_with(')') => {
                 let alpha = custom.trim_start_matches("custom(").trim_end_matches(')');
-                let bytes = alpha
-                    .as_bytes()
-                    .try_into()
-                    .context("custom alphabet is not 58 characters long")?;
-                Alphabet::Custom(bs58::Alphabet::new(bytes)?)
+                Alphabet::Custom(bs58::Alphabet::try_from(alpha.as_bytes())?)
             }
             other => {
                 return Err(anyhow!("'{}' is not a known alphabet", other));
@@ -51,6 +52,18 @@ impl FromStr for Alphabet {
     }
 }
 
+/// How the data side of the conversion is read or written: the input when encoding, the output
+/// when decoding.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    /// Raw bytes
+    Raw,
+    /// Hex encoded bytes
+    Hex,
+    /// Base58 encoded bytes, using the same alphabet
+    Base58,
+}
+
 #[derive(Debug, Parser)]
 #[command(about, version, disable_help_subcommand = true)]
 struct Args {
@@ -59,32 +72,133 @@ struct Args {
     decode: bool,
 
     /// Which base58 alphabet to decode/encode with [possible values: bitcoin, monero,
-    /// ripple, flickr or custom(abc...xyz)]
+    /// ripple, flickr, gmp or custom(abc...xyz)]
     #[arg(long, short = 'a', default_value = "bitcoin")]
     alphabet: Alphabet,
+
+    /// The format of the input when encoding, or of the output when decoding
+    #[arg(long, short = 'f', value_enum, default_value_t = Format::Raw)]
+    format: Format,
+
+    /// Read input from this file instead of stdin. With the `memmap` feature, base58 input is
+    /// memory-mapped rather than read into memory, so the file must not be modified while
+    /// decoding
+    #[arg(long)]
+    file: Option<PathBuf>,
 }
 
 const INITIAL_INPUT_CAPACITY: usize = 4096;
 
+fn decode_hex(input: &str) -> anyhow::Result<Vec<u8>> {
+    if !input.len().is_multiple_of(2) {
+        return Err(anyhow!("hex input has an odd number of digits"));
+    }
+    input
+        .as_bytes()
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            let digit = |offset: usize| {
+                char::from(pair[offset]).to_digit(16).ok_or_else(|| {
+                    anyhow!(
+                        "invalid hex character {:?} at index {}",
+                        char::from(pair[offset]),
+                        i * 2 + offset
+                    )
+                })
+            };
+            Ok((digit(0)? << 4 | digit(1)?) as u8)
+        })
+        .collect()
+}
+
+fn read_text(input: &mut impl Read) -> anyhow::Result<String> {
+    let mut text = String::with_capacity(INITIAL_INPUT_CAPACITY);
+    input.read_to_string(&mut text)?;
+    text.truncate(text.trim_end().len());
+    Ok(text)
+}
+
+fn read_data(
+    input: &mut impl Read,
+    format: Format,
+    alpha: &bs58::Alphabet,
+) -> anyhow::Result<Vec<u8>> {
+    Ok(match format {
+        Format::Raw => {
+            let mut data = Vec::with_capacity(INITIAL_INPUT_CAPACITY);
+            input.read_to_end(&mut data)?;
+            data
+        }
+        Format::Hex => decode_hex(&read_text(input)?)?,
+        Format::Base58 => bs58::decode(read_text(input)?)
+            .with_alphabet(alpha)
+            .into_vec()?,
+    })
+}
+
+fn open(path: &Path) -> anyhow::Result<File> {
+    File::open(path).with_context(|| format!("failed to open {}", path.display()))
+}
+
+/// Decode a base58 file in place, avoiding copying it into memory first.
+#[cfg(feature = "memmap")]
+fn decode_mapped(path: &Path, alpha: &bs58::Alphabet) -> anyhow::Result<Vec<u8>> {
+    let file = open(path)?;
+    // SAFETY: the file must not be modified or truncated while it is mapped, which we can't
+    // enforce; the map is only read here and is dropped before returning, and the `--file` help
+    // documents that the file must be left alone while decoding
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    Ok(bs58::decode(map.trim_ascii_end())
+        .with_alphabet(alpha)
+        .into_vec()?)
+}
+
+fn read_file(path: &Path, format: Format, alpha: &bs58::Alphabet) -> anyhow::Result<Vec<u8>> {
+    #[cfg(feature = "memmap")]
+    if let Format::Base58 = format {
+        return decode_mapped(path, alpha);
+    }
+    read_data(&mut open(path)?, format, alpha)
+}
+
+fn write_data(
+    output: &mut impl Write,
+    format: Format,
+    alpha: &bs58::Alphabet,
+    data: &[u8],
+) -> anyhow::Result<()> {
+    match format {
+        Format::Raw => output.write_all(data)?,
+        Format::Hex => data
+            .iter()
+            .try_for_each(|byte| write!(output, "{:02x}", byte))?,
+        Format::Base58 => output.write_all(
+            bs58::encode(data)
+                .with_alphabet(alpha)
+                .into_string()
+                .as_bytes(),
+        )?,
+    }
+    Ok(())
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    let alpha = args.alphabet.as_alphabet();
 
-    if args.decode {
-        let mut input = String::with_capacity(INITIAL_INPUT_CAPACITY);
-        io::stdin().read_to_string(&mut input)?;
-        let trimmed = input.trim_end();
-        let output = bs58::decode(trimmed)
-            .with_alphabet(args.alphabet.as_alphabet())
-            .into_vec()?;
-        io::stdout().write_all(&output)?;
+    // Every conversion goes through bytes, one side is always base58 and the other is `format`
+    let (input_format, output_format) = if args.decode {
+        (Format::Base58, args.format)
     } else {
-        let mut input = Vec::with_capacity(INITIAL_INPUT_CAPACITY);
-        io::stdin().read_to_end(&mut input)?;
-        let output = bs58::encode(input)
-            .with_alphabet(args.alphabet.as_alphabet())
-            .into_string();
-        io::stdout().write_all(output.as_bytes())?;
-    }
+        (args.format, Format::Base58)
+    };
+
+    let data = match &args.file {
+        Some(path) => read_file(path, input_format, alpha)?,
+        None => read_data(&mut io::stdin().lock(), input_format, alpha)?,
+    };
+    write_data(&mut io::stdout().lock(), output_format, alpha, &data)?;
 
     Ok(())
 }
@@ -0,0 +1,16 @@
+use std::io::{self, BufRead};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let pubkey = line.trim();
+        match bs58::solana::decode_pubkey(pubkey) {
+            Ok(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                println!("{}: {}", pubkey, hex);
+            }
+            Err(err) => println!("{}: invalid public key: {}", pubkey, err),
+        }
+    }
+    Ok(())
+}
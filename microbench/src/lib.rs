@@ -0,0 +1,121 @@
+#![no_std]
+#![warn(missing_copy_implementations)]
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+#![deny(unsafe_code)]
+
+//! A `no_std` harness for timing the scalar encode and decode paths of [`bs58`], for targets
+//! where `std` and so the usual benchmarking tools aren't available.
+//!
+//! Only the allocation free APIs are measured, encoding and decoding into fixed size buffers.
+//! The time source is provided by the caller through [`Clock`], e.g. a cycle counter register or
+//! a hardware timer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Instant;
+//!
+//! struct Nanos(Instant);
+//!
+//! impl bs58_microbench::Clock for Nanos {
+//!     fn now(&mut self) -> u64 {
+//!         self.0.elapsed().as_nanos() as u64
+//!     }
+//! }
+//!
+//! bs58_microbench::run(&mut Nanos(Instant::now()), 10, |measurement| {
+//!     println!("{}: {} ns/iter", measurement.name, measurement.per_iteration());
+//! });
+//! ```
+
+/// A monotonic time source, in whatever unit is convenient for the target.
+pub trait Clock {
+    /// The current time.
+    fn now(&mut self) -> u64;
+}
+
+/// The time taken by one benchmark.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Measurement {
+    /// The name of the benchmark, e.g. `"encode_32_bytes"`.
+    pub name: &'static str,
+    /// How many times the benchmark was run.
+    pub iterations: u32,
+    /// The total time taken by all iterations, in the units of the [`Clock`].
+    pub total: u64,
+}
+
+impl Measurement {
+    /// The average time taken by one iteration, in the units of the [`Clock`].
+    pub fn per_iteration(&self) -> u64 {
+        self.total / u64::from(self.iterations.max(1))
+    }
+}
+
+/// An input to encode and its encoding, to check the result against.
+struct Case {
+    encode_name: &'static str,
+    decode_name: &'static str,
+    decoded: &'static [u8],
+    encoded: &'static str,
+}
+
+const CASES: &[Case] = &[
+    Case {
+        encode_name: "encode_10_bytes",
+        decode_name: "decode_10_bytes",
+        decoded: &[0xec, 0xac, 0x89, 0xca, 0xd9, 0x39, 0x23, 0xc0, 0x23, 0x21],
+        encoded: "EJDM8drfXA6uyA",
+    },
+    Case {
+        encode_name: "encode_32_bytes",
+        decode_name: "decode_32_bytes",
+        decoded: &[
+            0x57, 0x2e, 0x47, 0x94, 0x57, 0x2e, 0x47, 0x94, 0x57, 0x2e, 0x47, 0x94, 0x57, 0x2e,
+            0x47, 0x94, 0x57, 0x2e, 0x47, 0x94, 0x57, 0x2e, 0x47, 0x94, 0x57, 0x2e, 0x47, 0x94,
+            0x57, 0x2e, 0x47, 0x94,
+        ],
+        encoded: "6sKQBLXudevN17UAQWYawBzr2ssjRQyuAdmdBN4r9HDm",
+    },
+];
+
+/// Run each benchmark `iterations` times, passing the measurement for each to `report`.
+///
+/// # Panics
+///
+/// If any of the encodings or decodings gives the wrong result, as the timings would be
+/// meaningless.
+pub fn run(clock: &mut impl Clock, iterations: u32, mut report: impl FnMut(Measurement)) {
+    for case in CASES {
+        let mut output = [0; 64];
+        let total = time(clock, iterations, || {
+            let len = bs58::encode(case.decoded).onto(&mut output[..]).unwrap();
+            assert_eq!(case.encoded.as_bytes(), &output[..len]);
+        });
+        report(Measurement {
+            name: case.encode_name,
+            iterations,
+            total,
+        });
+
+        let mut output = [0; 64];
+        let total = time(clock, iterations, || {
+            let len = bs58::decode(case.encoded).onto(&mut output[..]).unwrap();
+            assert_eq!(case.decoded, &output[..len]);
+        });
+        report(Measurement {
+            name: case.decode_name,
+            iterations,
+            total,
+        });
+    }
+}
+
+fn time(clock: &mut impl Clock, iterations: u32, mut f: impl FnMut()) -> u64 {
+    let start = clock.now();
+    for _ in 0..iterations {
+        f();
+    }
+    clock.now().saturating_sub(start)
+}
@@ -0,0 +1,48 @@
+use bs58_microbench::{Clock, Measurement};
+
+/// Advances by one unit each time it is read, so the totals are deterministic.
+struct Counter(u64);
+
+impl Clock for Counter {
+    fn now(&mut self) -> u64 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[test]
+fn test_run() {
+    let mut measurements = Vec::new();
+    bs58_microbench::run(&mut Counter(0), 5, |m| measurements.push(m));
+
+    let names: Vec<_> = measurements.iter().map(|m| m.name).collect();
+    assert_eq!(
+        names,
+        [
+            "encode_10_bytes",
+            "decode_10_bytes",
+            "encode_32_bytes",
+            "decode_32_bytes"
+        ]
+    );
+    for measurement in measurements {
+        assert_eq!(5, measurement.iterations);
+        assert_eq!(1, measurement.total);
+    }
+}
+
+#[test]
+fn test_per_iteration() {
+    let measurement = Measurement {
+        name: "test",
+        iterations: 4,
+        total: 100,
+    };
+    assert_eq!(25, measurement.per_iteration());
+
+    let measurement = Measurement {
+        iterations: 0,
+        ..measurement
+    };
+    assert_eq!(100, measurement.per_iteration());
+}
@@ -5,7 +5,31 @@ use core::fmt;
 /// Prepared Alphabet for
 /// [`EncodeBuilder::with_alphabet`](crate::encode::EncodeBuilder::with_alphabet) and
 /// [`DecodeBuilder::with_alphabet`](crate::decode::DecodeBuilder::with_alphabet).
-#[derive(Clone, Copy)]
+///
+/// # Adding an alphabet
+///
+/// Alphabets used by real-world formats can be added to this crate as an associated constant
+/// created with [`Alphabet::new_unwrap`], so that an invalid alphabet fails to compile. Include
+/// a link to a reference defining it in the documentation, and a test that it round trips
+/// correctly using [`Alphabet::self_test`] along with a known encoding from that reference.
+///
+/// # Migrating from raw alphabets
+///
+/// Older versions accepted a raw `&[u8; 58]` on every call. Prepare the alphabet once instead,
+/// in a `const` if it is known at compile time, and pass a reference to it:
+///
+/// ```rust
+/// const RAW: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+/// const ALPHA: &bs58::Alphabet = &bs58::Alphabet::new_unwrap(RAW);
+///
+/// // was `"he11owor1d".from_base58_with_alphabet(RAW)`
+/// let decoded = bs58::decode("he11owor1d").with_alphabet(ALPHA).into_vec()?;
+/// // was `decoded.to_base58_with_alphabet(RAW)`
+/// let encoded = bs58::encode(decoded).with_alphabet(ALPHA).into_string();
+/// assert_eq!("he11owor1d", encoded);
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Alphabet {
     pub(crate) encode: [u8; 58],
     pub(crate) decode: [u8; 128],
@@ -30,6 +54,38 @@ pub enum Error {
         /// The index at which the non-ASCII character was seen.
         index: usize,
     },
+
+    /// The alphabet was not exactly 58 characters long.
+    WrongLength {
+        /// The expected length, always 58.
+        expected: usize,
+        /// The length of the given alphabet.
+        actual: usize,
+    },
+
+    /// An alias given to [`AlphabetBuilder::alias`] was non-ASCII or already valid for decoding,
+    /// or the character it aliases was not part of the alphabet.
+    InvalidAlias {
+        /// The alias character.
+        alias: char,
+        /// The character it was to be an alias for.
+        character: char,
+    },
+
+    /// Encoding and then decoding data with the alphabet in [`Alphabet::self_test`] did not give
+    /// back the original data.
+    RoundTripFailed,
+}
+
+/// A builder for an [`Alphabet`] that accepts extra alias characters when decoding, created by
+/// [`Alphabet::builder`].
+///
+/// Encoding always uses the 58 base characters, while each alias decodes to the same value as the
+/// character it aliases. This allows lenient decoding, e.g. of characters commonly confused when
+/// transcribing, without affecting the encoded output.
+#[derive(Clone, Copy, Debug)]
+pub struct AlphabetBuilder {
+    alphabet: Result<Alphabet, Error>,
 }
 
 impl Alphabet {
@@ -57,6 +113,19 @@ impl Alphabet {
     pub const FLICKR: &'static Self =
         &Self::new_unwrap(b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ");
 
+    /// The digits used by the [GMP][] library (and others following it, such as Python's
+    /// `gmpy2`) for base 58 when converting integers to and from strings, the first 58 of
+    /// `0-9A-Za-z`.
+    ///
+    /// Unlike the other alphabets this includes the visually ambiguous characters `0`, `O`, `I`
+    /// and `l`, see [`Alphabet::is_unambiguous`].
+    ///
+    /// See <https://gmplib.org/manual/Converting-Integers>
+    ///
+    /// [GMP]: https://gmplib.org
+    pub const GMP: &'static Self =
+        &Self::new_unwrap(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuv");
+
     /// The default alphabet used if none is given. Currently is the
     /// [`BITCOIN`](Self::BITCOIN) alphabet.
     pub const DEFAULT: &'static Self = Self::BITCOIN;
@@ -64,6 +133,10 @@ impl Alphabet {
     /// Create prepared alphabet, checks that the alphabet is pure ASCII and that there are no
     /// duplicate characters, which would result in inconsistent encoding/decoding
     ///
+    /// Each character represents the digit of its index, so the first character is the zero
+    /// digit, which is also used to encode each leading zero byte of the input. An alphabet with a
+    /// different zero character is just a differently ordered alphabet, with that character first.
+    ///
     /// ```rust
     /// let alpha = bs58::Alphabet::new(
     ///     b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXY"
@@ -123,6 +196,33 @@ impl Alphabet {
         Ok(Self { encode, decode })
     }
 
+    /// Start building an alphabet with the given characters, to which decode-only aliases can
+    /// be added.
+    ///
+    /// Any errors from [`Self::new`] are returned by [`AlphabetBuilder::build`].
+    ///
+    /// ```rust
+    /// const BASE: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    /// const ALPHA: bs58::Alphabet = match bs58::Alphabet::builder(BASE)
+    ///     .alias(b'0', b'o')
+    ///     .alias(b'l', b'1')
+    ///     .build()
+    /// {
+    ///     Ok(alpha) => alpha,
+    ///     Err(_) => panic!(),
+    /// };
+    ///
+    /// let decoded = bs58::decode("he1lowor1d").with_alphabet(&ALPHA).into_vec()?;
+    /// assert_eq!(decoded, bs58::decode("he11owor1d").into_vec()?);
+    /// assert_eq!("he11owor1d", bs58::encode(decoded).with_alphabet(&ALPHA).into_string());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub const fn builder(base: &[u8; 58]) -> AlphabetBuilder {
+        AlphabetBuilder {
+            alphabet: Self::new(base),
+        }
+    }
+
     /// Same as [`Self::new`], but gives a panic instead of an [`Err`] on bad input.
     ///
     /// Intended to support usage in `const` context until [`Result::unwrap`] is able to be called.
@@ -158,6 +258,280 @@ impl Alphabet {
             Err(_) => 0,
         }]
     }
+
+    /// Whether the character is valid for decoding with this alphabet, including any aliases added
+    /// with [`AlphabetBuilder::alias`], e.g. for cheaply filtering input.
+    ///
+    /// ```rust
+    /// const VALID: bool = bs58::Alphabet::BITCOIN.contains(b'z');
+    /// assert!(VALID);
+    /// assert!(!bs58::Alphabet::BITCOIN.contains(b'0'));
+    /// assert!(!bs58::Alphabet::BITCOIN.contains(0xC3));
+    /// ```
+    pub const fn contains(&self, c: u8) -> bool {
+        c < 128 && self.decode[c as usize] < 58
+    }
+
+    /// Whether the character decodes to zero, i.e. is the zero character or an alias of it.
+    pub(crate) const fn is_zero(&self, c: u8) -> bool {
+        c < 128 && self.decode[c as usize] == 0
+    }
+
+    /// The characters valid for decoding with this alphabet, sorted in ascending ASCII order.
+    ///
+    /// This excludes any aliases added with [`AlphabetBuilder::alias`], which are included in
+    /// [`Self::valid_char_set`].
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    ///     &bs58::Alphabet::RIPPLE.valid_chars());
+    /// ```
+    pub const fn valid_chars(&self) -> [u8; 58] {
+        let mut chars = [0; 58];
+        let mut len = 0;
+        let mut c = 0;
+        while c < self.decode.len() {
+            if self.decode[c] != 0xFF && self.encode[self.decode[c] as usize] == c as u8 {
+                chars[len] = c as u8;
+                len += 1;
+            }
+            c += 1;
+        }
+        chars
+    }
+
+    /// Check that data encoded with this alphabet decodes back to the original data, for a set of
+    /// representative inputs including every single byte value, leading zeros and long runs of
+    /// `0xFF`.
+    ///
+    /// Alphabets created by [`Alphabet::new`] always pass, this is intended as a sanity check at
+    /// startup or in tests for alphabets loaded at runtime.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::RoundTripFailed`] if any input does not round trip.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// bs58::Alphabet::RIPPLE.self_test()?;
+    /// # Ok::<(), bs58::alphabet::Error>(())
+    /// ```
+    pub fn self_test(&self) -> Result<(), Error> {
+        let mut sequence = [0; 58];
+        for (i, byte) in sequence.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let patterns: [&[u8]; 5] = [&[], &[0, 0, 0xFF], &[0xFF; 32], &[0; 32], &sequence];
+
+        for input in patterns {
+            self.round_trip(input)?;
+        }
+        for byte in 0..=u8::MAX {
+            self.round_trip(&[byte])?;
+        }
+        Ok(())
+    }
+
+    fn round_trip(&self, input: &[u8]) -> Result<(), Error> {
+        let (mut encoded, mut decoded) = ([0; 128], [0; 64]);
+        let encoded_len = crate::encode(input)
+            .with_alphabet(self)
+            .onto(&mut encoded[..])
+            .map_err(|_| Error::RoundTripFailed)?;
+        let decoded_len = crate::decode(&encoded[..encoded_len])
+            .with_alphabet(self)
+            .onto(&mut decoded[..])
+            .map_err(|_| Error::RoundTripFailed)?;
+        if &decoded[..decoded_len] != input {
+            return Err(Error::RoundTripFailed);
+        }
+        Ok(())
+    }
+
+    /// Whether this alphabet avoids the visually ambiguous characters `0`, `O`, `I` and `l`
+    /// which base58 was designed to exclude, so that encoded output is safe for human
+    /// transcription. Tools accepting custom alphabets can use this to warn about them.
+    ///
+    /// ```rust
+    /// assert!(bs58::Alphabet::BITCOIN.is_unambiguous());
+    ///
+    /// let alpha = bs58::Alphabet::new(
+    ///     b"0123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxy")?;
+    /// assert!(!alpha.is_unambiguous());
+    /// # Ok::<(), bs58::alphabet::Error>(())
+    /// ```
+    pub const fn is_unambiguous(&self) -> bool {
+        const AMBIGUOUS: &[u8] = b"0OIl";
+
+        let mut i = 0;
+        while i < self.encode.len() {
+            let mut j = 0;
+            while j < AMBIGUOUS.len() {
+                if self.encode[i] == AMBIGUOUS[j] {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Create a copy of this alphabet with every lowercase letter replaced by its uppercase
+    /// form, e.g. to derive an uppercase alphabet for display from a lowercase one.
+    ///
+    /// Returns `None` if that would create duplicates because a letter is used in both cases,
+    /// as in all the built-in alphabets. Any aliases from [`Alphabet::builder`] are not copied.
+    ///
+    /// ```rust
+    /// let lower = bs58::Alphabet::new(
+    ///     b"0123456789abcdefghijklmnopqrstuvwxyz!#$%&()*+,-./:;<=>?@[]")?;
+    /// let upper = lower.to_uppercase_variant().unwrap();
+    /// assert_eq!("HE110", bs58::transcode("he110", &lower, &upper)?);
+    ///
+    /// assert_eq!(None, bs58::Alphabet::BITCOIN.to_uppercase_variant());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn to_uppercase_variant(&self) -> Option<Alphabet> {
+        let mut base = self.encode;
+        let mut i = 0;
+        while i < base.len() {
+            base[i] = base[i].to_ascii_uppercase();
+            i += 1;
+        }
+        match Alphabet::new(&base) {
+            Ok(alphabet) => Some(alphabet),
+            Err(_) => None,
+        }
+    }
+
+    /// Create a copy of this alphabet with every uppercase letter replaced by its lowercase
+    /// form, the counterpart to [`Alphabet::to_uppercase_variant`].
+    ///
+    /// Returns `None` if that would create duplicates because a letter is used in both cases.
+    /// Any aliases from [`Alphabet::builder`] are not copied.
+    ///
+    /// ```rust
+    /// let upper = bs58::Alphabet::new(
+    ///     b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ!#$%&()*+,-./:;<=>?@[]")?;
+    /// let lower = upper.to_lowercase_variant().unwrap();
+    /// assert_eq!(lower.to_uppercase_variant(), Some(upper));
+    ///
+    /// assert_eq!(None, bs58::Alphabet::FLICKR.to_lowercase_variant());
+    /// # Ok::<(), bs58::alphabet::Error>(())
+    /// ```
+    pub const fn to_lowercase_variant(&self) -> Option<Alphabet> {
+        let mut base = self.encode;
+        let mut i = 0;
+        while i < base.len() {
+            base[i] = base[i].to_ascii_lowercase();
+            i += 1;
+        }
+        match Alphabet::new(&base) {
+            Ok(alphabet) => Some(alphabet),
+            Err(_) => None,
+        }
+    }
+
+    /// The characters valid for decoding with this alphabet as a bitmask over ASCII, bit `c` is
+    /// set if character `c` is valid. This allows checking input characters without a lookup
+    /// table, e.g. for masking input in a UI.
+    ///
+    /// ```rust
+    /// let valid = bs58::Alphabet::BITCOIN.valid_char_set();
+    /// assert!(valid & (1 << b'z') != 0);
+    /// assert!(valid & (1 << b'0') == 0);
+    /// assert_eq!(58, valid.count_ones());
+    /// ```
+    pub const fn valid_char_set(&self) -> u128 {
+        let mut set = 0;
+        let mut c = 0;
+        while c < self.decode.len() {
+            if self.decode[c] != 0xFF {
+                set |= 1 << c;
+            }
+            c += 1;
+        }
+        set
+    }
+}
+
+impl AlphabetBuilder {
+    /// Also decode `alias` as the value of `character`, which must be part of the alphabet.
+    ///
+    /// If the alias is invalid [`Error::InvalidAlias`] is returned by [`Self::build`].
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bs58::alphabet::Error::InvalidAlias { alias: '2', character: '1' },
+    ///     bs58::Alphabet::builder(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+    ///         .alias(b'2', b'1')
+    ///         .build()
+    ///         .unwrap_err());
+    /// ```
+    pub const fn alias(self, alias: u8, character: u8) -> AlphabetBuilder {
+        let Ok(mut alphabet) = self.alphabet else {
+            return self;
+        };
+        if alias >= 128
+            || character >= 128
+            || alphabet.decode[alias as usize] != 0xFF
+            || alphabet.decode[character as usize] == 0xFF
+        {
+            return AlphabetBuilder {
+                alphabet: Err(Error::InvalidAlias {
+                    alias: alias as char,
+                    character: character as char,
+                }),
+            };
+        }
+        alphabet.decode[alias as usize] = alphabet.decode[character as usize];
+        AlphabetBuilder {
+            alphabet: Ok(alphabet),
+        }
+    }
+
+    /// Finish building the alphabet, returning the first error encountered if any.
+    pub const fn build(self) -> Result<Alphabet, Error> {
+        self.alphabet
+    }
+}
+
+impl TryFrom<&[u8; 58]> for Alphabet {
+    type Error = Error;
+
+    /// Same as [`Alphabet::new`].
+    fn try_from(base: &[u8; 58]) -> Result<Self, Error> {
+        Self::new(base)
+    }
+}
+
+impl TryFrom<&[u8]> for Alphabet {
+    type Error = Error;
+
+    /// Create a prepared alphabet from runtime data, checking that it is exactly 58 characters
+    /// long along with the checks done by [`Alphabet::new`].
+    ///
+    /// ```rust
+    /// let base = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz".to_vec();
+    /// let alpha = bs58::Alphabet::try_from(base.as_slice())?;
+    /// assert_eq!("2g", bs58::encode([0x61]).with_alphabet(&alpha).into_string());
+    ///
+    /// assert_eq!(
+    ///     bs58::alphabet::Error::WrongLength { expected: 58, actual: 57 },
+    ///     bs58::Alphabet::try_from(&base[1..]).unwrap_err());
+    /// # Ok::<(), bs58::alphabet::Error>(())
+    /// ```
+    fn try_from(base: &[u8]) -> Result<Self, Error> {
+        let actual = base.len();
+        let base = <&[u8; 58]>::try_from(base).map_err(|_| Error::WrongLength {
+            expected: 58,
+            actual,
+        })?;
+        Self::new(base)
+    }
 }
 
 impl fmt::Debug for Alphabet {
@@ -188,16 +562,84 @@ impl fmt::Display for Error {
             Error::NonAsciiCharacter { index } => {
                 write!(f, "alphabet contained a non-ascii character at {}", index)
             }
+            Error::WrongLength { expected, actual } => write!(
+                f,
+                "alphabet was {} characters long, expected {}",
+                actual, expected,
+            ),
+            Error::InvalidAlias { alias, character } => write!(
+                f,
+                "alphabet alias {:?} for {:?} was not a new ascii character aliasing an existing one",
+                alias, character,
+            ),
+            Error::RoundTripFailed => {
+                write!(f, "data encoded with the alphabet did not decode to the original")
+            }
         }
     }
 }
 
+/// An alphabet known at compile time, for the monomorphized
+/// [`encode::encode_static_into`](crate::encode::encode_static_into) and
+/// [`decode::decode_static_into`](crate::decode::decode_static_into).
+///
+/// Implementors are usually uninhabited marker types, such as the ones for the built-in
+/// alphabets in this module. Because the alphabet is part of the type the compiler generates a
+/// copy of the codec specialized to it, with the table lookups resolved at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// enum Custom {}
+///
+/// impl bs58::alphabet::StaticAlphabet for Custom {
+///     const ALPHABET: &'static bs58::Alphabet = &bs58::Alphabet::new_unwrap(
+///         b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz",
+///     );
+/// }
+///
+/// let mut output = [0; 10];
+/// let len = bs58::encode::encode_static_into::<Custom>(&[0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], &mut output)?;
+/// assert_eq!(b"6erroAoird", &output[..len]);
+/// # Ok::<(), bs58::encode::Error>(())
+/// ```
+pub trait StaticAlphabet {
+    /// The alphabet to encode and decode with.
+    const ALPHABET: &'static Alphabet;
+}
+
+macro_rules! static_alphabets {
+    ($($(#[$attr:meta])* $name:ident => $alpha:ident,)*) => {$(
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $name {}
+
+        impl StaticAlphabet for $name {
+            const ALPHABET: &'static Alphabet = Alphabet::$alpha;
+        }
+    )*};
+}
+
+static_alphabets! {
+    /// The [`Alphabet::BITCOIN`] alphabet as a [`StaticAlphabet`].
+    Bitcoin => BITCOIN,
+    /// The [`Alphabet::MONERO`] alphabet as a [`StaticAlphabet`].
+    Monero => MONERO,
+    /// The [`Alphabet::RIPPLE`] alphabet as a [`StaticAlphabet`].
+    Ripple => RIPPLE,
+    /// The [`Alphabet::FLICKR`] alphabet as a [`StaticAlphabet`].
+    Flickr => FLICKR,
+    /// The [`Alphabet::GMP`] alphabet as a [`StaticAlphabet`].
+    Gmp => GMP,
+}
+
 // Force evaluation of the associated constants to make sure they don't error
 const _: () = {
     let _ = Alphabet::BITCOIN;
     let _ = Alphabet::MONERO;
     let _ = Alphabet::RIPPLE;
     let _ = Alphabet::FLICKR;
+    let _ = Alphabet::GMP;
     let _ = Alphabet::DEFAULT;
 };
 
@@ -206,3 +648,208 @@ const _: () = {
 fn test_new_unwrap_does_panic() {
     Alphabet::new_unwrap(b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_error_display() {
+    use alloc::string::ToString;
+
+    assert_eq!(
+        Error::WrongLength {
+            expected: 58,
+            actual: 3
+        }
+        .to_string(),
+        "alphabet was 3 characters long, expected 58"
+    );
+}
+
+#[test]
+fn test_builder_alias() {
+    let alpha = Alphabet::builder(&Alphabet::BITCOIN.encode)
+        .alias(b'0', b'1')
+        .alias(b'O', b'o')
+        .build()
+        .unwrap();
+    assert_eq!(alpha.encode, Alphabet::BITCOIN.encode);
+    assert_eq!(alpha.decode[b'0' as usize], 0);
+    assert_eq!(alpha.decode[b'O' as usize], alpha.decode[b'o' as usize]);
+    assert!(alpha.is_zero(b'0'));
+    assert_eq!(alpha.valid_chars(), Alphabet::BITCOIN.valid_chars());
+    assert_eq!(
+        alpha.valid_char_set(),
+        Alphabet::BITCOIN.valid_char_set() | 1 << b'0' | 1 << b'O'
+    );
+
+    for (alias, character) in [(b'1', b'2'), (b'0', b'0'), (0xff, b'1'), (b'0', 0xff)] {
+        assert_eq!(
+            Alphabet::builder(&Alphabet::BITCOIN.encode)
+                .alias(alias, character)
+                .build()
+                .unwrap_err(),
+            Error::InvalidAlias {
+                alias: alias as char,
+                character: character as char,
+            }
+        );
+    }
+
+    assert_eq!(
+        Alphabet::builder(&[b'a'; 58])
+            .alias(b'0', b'a')
+            .build()
+            .unwrap_err(),
+        Error::DuplicateCharacter {
+            character: 'a',
+            first: 0,
+            second: 1
+        }
+    );
+}
+
+#[test]
+fn test_is_unambiguous() {
+    for alpha in [
+        Alphabet::BITCOIN,
+        Alphabet::MONERO,
+        Alphabet::RIPPLE,
+        Alphabet::FLICKR,
+    ] {
+        assert!(alpha.is_unambiguous());
+    }
+
+    assert!(!Alphabet::GMP.is_unambiguous());
+
+    for c in *b"0OIl" {
+        let mut base = Alphabet::BITCOIN.encode;
+        base[57] = c;
+        assert!(!Alphabet::new(&base).unwrap().is_unambiguous());
+    }
+
+    // Aliases only affect decoding, so don't make the output ambiguous
+    let alpha = Alphabet::builder(&Alphabet::BITCOIN.encode)
+        .alias(b'0', b'1')
+        .build()
+        .unwrap();
+    assert!(alpha.is_unambiguous());
+}
+
+#[test]
+fn test_contains() {
+    let alpha = Alphabet::builder(&Alphabet::BITCOIN.encode)
+        .alias(b'0', b'1')
+        .build()
+        .unwrap();
+    for c in 0..=u8::MAX {
+        assert_eq!(
+            Alphabet::BITCOIN.valid_char_set() & (1 << (c & 127)) != 0 && c < 128,
+            Alphabet::BITCOIN.contains(c)
+        );
+        assert_eq!(
+            Alphabet::BITCOIN.contains(c) || c == b'0',
+            alpha.contains(c)
+        );
+    }
+}
+
+#[test]
+fn test_case_variants() {
+    for alpha in [
+        Alphabet::BITCOIN,
+        Alphabet::MONERO,
+        Alphabet::RIPPLE,
+        Alphabet::FLICKR,
+        Alphabet::GMP,
+    ] {
+        assert_eq!(None, alpha.to_uppercase_variant());
+        assert_eq!(None, alpha.to_lowercase_variant());
+    }
+
+    let lower =
+        Alphabet::new(b"0123456789abcdefghijklmnopqrstuvwxyz!#$%&()*+,-./:;<=>?@[]").unwrap();
+    let upper = lower.to_uppercase_variant().unwrap();
+    assert_eq!(
+        &upper.encode,
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ!#$%&()*+,-./:;<=>?@[]"
+    );
+    assert_eq!(Some(upper), upper.to_uppercase_variant());
+    assert_eq!(Some(lower), upper.to_lowercase_variant());
+    assert_eq!(Some(lower), lower.to_lowercase_variant());
+}
+
+#[test]
+fn test_self_test() {
+    for alpha in [
+        Alphabet::BITCOIN,
+        Alphabet::MONERO,
+        Alphabet::RIPPLE,
+        Alphabet::FLICKR,
+        Alphabet::GMP,
+    ] {
+        assert_eq!(Ok(()), alpha.self_test());
+    }
+
+    let alpha = Alphabet::builder(&Alphabet::BITCOIN.encode)
+        .alias(b'0', b'1')
+        .build()
+        .unwrap();
+    assert_eq!(Ok(()), alpha.self_test());
+
+    // Swap the decoded values of two characters
+    let mut alpha = *Alphabet::BITCOIN;
+    alpha.decode.swap(b'a' as usize, b'b' as usize);
+    assert_eq!(Err(Error::RoundTripFailed), alpha.self_test());
+
+    // Decode a character to an out of range value
+    let mut alpha = *Alphabet::BITCOIN;
+    alpha.decode[b'z' as usize] = 60;
+    assert_eq!(Err(Error::RoundTripFailed), alpha.self_test());
+}
+
+#[test]
+fn test_valid_chars() {
+    for alpha in [
+        Alphabet::BITCOIN,
+        Alphabet::MONERO,
+        Alphabet::RIPPLE,
+        Alphabet::FLICKR,
+    ] {
+        let mut sorted = alpha.encode;
+        sorted.sort_unstable();
+        assert_eq!(alpha.valid_chars(), sorted);
+
+        let set = alpha.valid_char_set();
+        for c in 0..128u8 {
+            assert_eq!(set & (1 << c) != 0, alpha.encode.contains(&c));
+        }
+    }
+}
+
+#[test]
+fn test_try_from_slice() {
+    let base = Alphabet::BITCOIN.encode;
+    assert!(Alphabet::try_from(&base).is_ok());
+    assert!(Alphabet::try_from(&base[..]).is_ok());
+    assert_eq!(
+        Alphabet::try_from(&base[..57]).unwrap_err(),
+        Error::WrongLength {
+            expected: 58,
+            actual: 57
+        }
+    );
+    assert_eq!(
+        Alphabet::try_from(&[b'a'; 59][..]).unwrap_err(),
+        Error::WrongLength {
+            expected: 58,
+            actual: 59
+        }
+    );
+    assert_eq!(
+        Alphabet::try_from(&[b'a'; 58][..]).unwrap_err(),
+        Error::DuplicateCharacter {
+            character: 'a',
+            first: 0,
+            second: 1
+        }
+    );
+}
@@ -1,25 +1,73 @@
 //! Functions for decoding Base58 encoded strings.
+//!
+//! # Padding
+//!
+//! Unlike base64, base58 has no notion of padding, so there is no "strict" mode rejecting padded
+//! input. Leading zero characters each represent a leading zero byte, while any other zero
+//! characters (including trailing ones) are digits of the encoded number. This means every string
+//! made up of characters from the alphabet is the one canonical encoding of its decoded bytes:
+//!
+//! ```rust
+//! assert_eq!(vec![0x61], bs58::decode("2g").into_vec()?);
+//! assert_eq!(vec![0x15, 0xfa], bs58::decode("2g1").into_vec()?);
+//! assert_eq!(vec![0x00, 0x61], bs58::decode("12g").into_vec()?);
+//! # Ok::<(), bs58::decode::Error>(())
+//! ```
 
-use core::fmt;
+#[cfg(feature = "check-core")]
+use core::ops::{Bound, RangeBounds};
+use core::{fmt, marker::PhantomData};
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
-use crate::Check;
-#[cfg(any(feature = "check", feature = "cb58"))]
-use crate::CHECKSUM_LEN;
+#[cfg(feature = "check-core")]
+use crate::Sha256IncrementalFn;
+#[cfg(feature = "check-core")]
+use crate::Version;
+use crate::{Check, CheckFn, MAX_CHECK_FN_LEN};
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+use crate::{Sha256, Sha256Fn, CHECKSUM_LEN};
 
-use crate::Alphabet;
+use crate::{
+    alphabet::StaticAlphabet,
+    int::{Endianness, Int},
+    Alphabet, DefaultSha256,
+};
 
 /// A builder for setting up the alphabet and output of a base58 decode.
 ///
 /// See the documentation for [`bs58::decode`](crate::decode()) for a more
 /// high level view of how to use this.
+///
+/// `H` is the SHA-256 implementation used to verify checksums, see
+/// [`DefaultSha256`].
 #[allow(missing_debug_implementations)]
-pub struct DecodeBuilder<'a, I: AsRef<[u8]>> {
+pub struct DecodeBuilder<'a, I: AsRef<[u8]>, H = DefaultSha256> {
     input: I,
     alpha: &'a Alphabet,
-    check: Check,
+    check: Check<'a>,
+    #[cfg(feature = "check-core")]
+    check_range: (Bound<usize>, Bound<usize>),
+    #[cfg(feature = "check-core")]
+    trailing_version: bool,
+    replace_target: bool,
+    #[cfg(feature = "alloc")]
+    exact_prealloc: bool,
+    padded: bool,
+    prefix: &'a [u8],
+    delimiter: Option<u8>,
+    crc32_footer: bool,
+    expected_len: Option<usize>,
+    expected_leading_zeros: Option<usize>,
+    sanitize_unicode: bool,
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+    sha256: Sha256Fn,
+    #[cfg(feature = "check-core")]
+    sha256_incremental: Sha256IncrementalFn,
+    #[cfg(feature = "percent-encoding")]
+    percent_decode: bool,
+    hasher: PhantomData<fn() -> H>,
 }
 
 /// A specialized [`Result`](core::result::Result) type for [`bs58::decode`](module@crate::decode)
@@ -49,7 +97,7 @@ pub enum Error {
         index: usize,
     },
 
-    #[cfg(any(feature = "check", feature = "cb58"))]
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
     /// The checksum did not match the payload bytes
     InvalidChecksum {
         ///The given checksum
@@ -58,7 +106,7 @@ pub enum Error {
         expected_checksum: [u8; CHECKSUM_LEN],
     },
 
-    #[cfg(any(feature = "check", feature = "cb58"))]
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
     /// The version did not match the payload bytes
     InvalidVersion {
         ///The given version
@@ -67,9 +115,141 @@ pub enum Error {
         expected_ver: u8,
     },
 
-    #[cfg(any(feature = "check", feature = "cb58"))]
-    ///Not enough bytes to have both a checksum and a payload (less than to CHECKSUM_LEN)
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+    ///Not enough bytes to have both a checksum and a payload (less than to CHECKSUM_LEN), or no
+    ///checksum segment of exactly CHECKSUM_LEN bytes when using
+    ///[`DecodeBuilder::with_check_delimited`]
     NoChecksum,
+
+    #[cfg(feature = "check-core")]
+    /// Not enough bytes before the checksum to have both a network and version byte, when
+    /// decoding with [`DecodeBuilder::into_check_network_version`].
+    NoNetworkVersion,
+
+    /// The input was shorter than its length prefix required.
+    InputTooShort {
+        /// The length required by the length prefix, including the prefix itself.
+        expected: usize,
+        /// The actual length of the input.
+        actual: usize,
+    },
+
+    #[cfg(feature = "percent-encoding")]
+    /// The input contained a `%` that was not followed by two hexadecimal digits.
+    InvalidPercentEncoding {
+        /// The (byte) index in the input string the `%` was at.
+        index: usize,
+    },
+
+    #[cfg(feature = "check-core")]
+    /// The range given to [`DecodeBuilder::with_check_over`] was not within the decoded payload.
+    InvalidChecksumRange {
+        /// The start of the range (inclusive).
+        start: usize,
+        /// The end of the range (exclusive).
+        end: usize,
+        /// The length of the decoded payload, excluding the checksum.
+        len: usize,
+    },
+
+    /// The input did not start with the prefix given to [`DecodeBuilder::strip_prefix`].
+    MissingPrefix,
+
+    /// The decoded data was not a whole number of packed values, see
+    /// [`bs58::decode_u32_slice`](crate::decode_u32_slice()).
+    UnalignedLength {
+        /// The length of the decoded data.
+        len: usize,
+        /// The size of each packed value.
+        alignment: usize,
+    },
+
+    /// The decoded data was not the length given to [`DecodeBuilder::expect_len`].
+    WrongLength {
+        /// The expected length.
+        expected: usize,
+        /// The length of the decoded data.
+        actual: usize,
+    },
+
+    /// The CRC-32 footer given to [`DecodeBuilder::with_crc32_footer`] did not match the input.
+    InvalidCrc32 {
+        /// The CRC-32 calculated for the input before the delimiter.
+        crc: u32,
+        /// The CRC-32 read from the footer.
+        expected_crc: u32,
+    },
+
+    /// The checksum calculated by the function given to [`DecodeBuilder::with_check_fn`] did not
+    /// match the end of the decoded data, or the decoded data was shorter than the checksum.
+    InvalidCheckFnChecksum,
+
+    /// The decoded data did not start with the number of zero bytes given to
+    /// [`DecodeBuilder::expect_leading_zeros`].
+    WrongLeadingZeros {
+        /// The expected number of leading zero bytes.
+        expected: usize,
+        /// The number of leading zero bytes in the decoded data.
+        actual: usize,
+    },
+
+    /// The decoded data was longer than the integer returned by [`DecodeBuilder::into_u64`] or
+    /// [`DecodeBuilder::into_int`].
+    IntegerOverflow,
+
+    /// The decoded value was zero when decoding with [`DecodeBuilder::into_nonzero_u64`].
+    IntegerZero,
+
+    /// The input to [`bs58::decode_uuid`](crate::decode_uuid) was not
+    /// [`UUID_ENCODED_LEN`](crate::UUID_ENCODED_LEN) characters long.
+    InvalidUuidLength {
+        /// The length of the input.
+        len: usize,
+    },
+}
+
+/// An [`Error`] along with the alphabet that was being decoded with, created by
+/// [`Error::with_alphabet`].
+///
+/// The [`Display`](fmt::Display) implementation includes a hint for [`Error::InvalidCharacter`],
+/// either suggesting the [case variant](VerboseError::suggestion) of the character if that is
+/// valid, or listing the valid characters of the alphabet.
+#[derive(Copy, Clone, Debug)]
+pub struct VerboseError<'a> {
+    /// The underlying error.
+    pub error: Error,
+    /// The alphabet that was being decoded with.
+    pub alphabet: &'a Alphabet,
+}
+
+/// The checksum algorithm used by [`bs58::inspect_check`](crate::inspect_check()).
+#[cfg(all(any(feature = "check-core", feature = "cb58-core"), feature = "alloc"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CheckScheme {
+    /// [Base58Check](https://en.bitcoin.it/wiki/Base58Check_encoding), see
+    /// [`DecodeBuilder::with_check`].
+    #[cfg(feature = "check-core")]
+    Check,
+    /// [CB58](https://support.avax.network/en/articles/4587395-what-is-cb58), see
+    /// [`DecodeBuilder::as_cb58`].
+    #[cfg(feature = "cb58-core")]
+    CB58,
+}
+
+/// The components of a checked encoding, returned by
+/// [`bs58::inspect_check`](crate::inspect_check()).
+#[cfg(all(any(feature = "check-core", feature = "cb58-core"), feature = "alloc"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckParts {
+    /// The first byte of the data, if there is any.
+    pub version: Option<u8>,
+    /// The data after the version, excluding the checksum.
+    pub payload: Vec<u8>,
+    /// The checksum included in the input.
+    pub checksum: [u8; CHECKSUM_LEN],
+    /// Whether the included checksum matches the one calculated from the data.
+    pub checksum_valid: bool,
 }
 
 /// Represents a buffer that can be decoded into. See [`DecodeBuilder::onto`] and the provided
@@ -83,6 +263,11 @@ pub trait DecodeTarget {
         max_len: usize,
         f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
     ) -> Result<usize>;
+
+    /// Removes any existing data from a resizable buffer, called before decoding when
+    /// [`DecodeBuilder::replace_target`] is used. Fixed size buffers are always written from the
+    /// start, so by default this does nothing.
+    fn clear(&mut self) {}
 }
 
 impl<T: DecodeTarget + ?Sized> DecodeTarget for &mut T {
@@ -93,6 +278,10 @@ impl<T: DecodeTarget + ?Sized> DecodeTarget for &mut T {
     ) -> Result<usize> {
         T::decode_with(self, max_len, f)
     }
+
+    fn clear(&mut self) {
+        T::clear(self)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -108,6 +297,10 @@ impl DecodeTarget for Vec<u8> {
         self.truncate(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        Vec::clear(self)
+    }
 }
 
 #[cfg(feature = "smallvec")]
@@ -124,6 +317,30 @@ impl<A: smallvec::Array<Item = u8>> DecodeTarget for smallvec::SmallVec<A> {
         self.truncate(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        smallvec::SmallVec::clear(self)
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+impl DecodeTarget for bumpalo::collections::Vec<'_, u8> {
+    /// Decodes data into a [`bumpalo::collections::Vec`], allocating from its arena.
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        let original = self.len();
+        self.resize(original + max_len, 0);
+        let len = f(&mut self[original..])?;
+        self.truncate(original + len);
+        Ok(len)
+    }
+
+    fn clear(&mut self) {
+        bumpalo::collections::Vec::clear(self)
+    }
 }
 
 #[cfg(feature = "tinyvec")]
@@ -139,6 +356,10 @@ impl<A: tinyvec::Array<Item = u8>> DecodeTarget for tinyvec::ArrayVec<A> {
         self.set_len(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        tinyvec::ArrayVec::clear(self)
+    }
 }
 
 #[cfg(feature = "tinyvec")]
@@ -154,6 +375,10 @@ impl DecodeTarget for tinyvec::SliceVec<'_, u8> {
         self.set_len(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        tinyvec::SliceVec::clear(self)
+    }
 }
 
 #[cfg(all(feature = "tinyvec", feature = "alloc"))]
@@ -169,6 +394,131 @@ impl<A: tinyvec::Array<Item = u8>> DecodeTarget for tinyvec::TinyVec<A> {
         self.truncate(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        tinyvec::TinyVec::clear(self)
+    }
+}
+
+impl<I: AsRef<[u8]>, H> DecodeBuilder<'_, I, H> {
+    /// Returns the part of the input before any delimiter set by [`Self::until_delimiter`], after
+    /// verifying any CRC-32 footer, along with the total length of the frame.
+    fn frame<'i>(&self, input: &'i [u8]) -> Result<(&'i [u8], usize)> {
+        let Some(delimiter) = self.delimiter else {
+            return Ok((input, input.len()));
+        };
+        let Some(end) = input.iter().position(|c| *c == delimiter) else {
+            if self.crc32_footer {
+                return Err(Error::InputTooShort {
+                    expected: input.len().saturating_add(5),
+                    actual: input.len(),
+                });
+            }
+            return Ok((input, input.len()));
+        };
+        if !self.crc32_footer {
+            return Ok((&input[..end], end + 1));
+        }
+
+        let footer = input.get(end + 1..end + 5).ok_or(Error::InputTooShort {
+            expected: end + 5,
+            actual: input.len(),
+        })?;
+        let crc = crc32(&input[..end]);
+        let expected_crc = u32::from_be_bytes([footer[0], footer[1], footer[2], footer[3]]);
+        if crc != expected_crc {
+            return Err(Error::InvalidCrc32 { crc, expected_crc });
+        }
+        Ok((&input[..end], end + 5))
+    }
+
+    /// Applies the options preparing the input for decoding: the frame, Unicode sanitization, the
+    /// prefix, percent-decoding and padding. Calls `f` with the characters left to decode along
+    /// with the number of bytes skipped before them, for adjusting error indexes.
+    fn with_normalized<T>(
+        &self,
+        input: &[u8],
+        f: impl FnOnce(&[u8], usize) -> Result<T>,
+    ) -> Result<T> {
+        let (input, _) = self.frame(input)?;
+        let (input, sanitized) = if self.sanitize_unicode {
+            sanitize_unicode(input)
+        } else {
+            (input, 0)
+        };
+        let input = input
+            .strip_prefix(self.prefix)
+            .ok_or(Error::MissingPrefix)?;
+
+        #[cfg(feature = "percent-encoding")]
+        let percent_decoded;
+        #[cfg(feature = "percent-encoding")]
+        let input = if self.percent_decode {
+            percent_decoded = percent_decode(input)?;
+            &percent_decoded[..]
+        } else {
+            input
+        };
+
+        let padding = if self.padded {
+            input.iter().take_while(|c| self.alpha.is_zero(**c)).count()
+        } else {
+            0
+        };
+
+        f(&input[padding..], sanitized + self.prefix.len() + padding)
+    }
+}
+
+/// The CRC-32 used by zlib, PNG and others, with the reflected polynomial `0xEDB88320`.
+fn crc32(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    !data.iter().fold(!0, |crc, byte| {
+        TABLE[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Decodes into a temporary buffer, then passes only the exact decoded length on to the wrapped
+/// target, for [`DecodeBuilder::exact_prealloc`].
+#[cfg(feature = "alloc")]
+struct ExactLen<T>(T);
+
+#[cfg(feature = "alloc")]
+impl<T: DecodeTarget> DecodeTarget for ExactLen<T> {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        let mut decoded = alloc::vec![0; max_len];
+        let len = f(&mut decoded)?;
+        self.0.decode_with(len, |output| {
+            output
+                .get_mut(..len)
+                .ok_or(Error::BufferTooSmall)?
+                .copy_from_slice(&decoded[..len]);
+            Ok(len)
+        })
+    }
 }
 
 impl DecodeTarget for [u8] {
@@ -193,6 +543,41 @@ impl<const N: usize> DecodeTarget for [u8; N] {
     }
 }
 
+/// A fixed size field which the decoded data is written to the start of, with the rest of the
+/// field filled with zeros, for fixed-record formats that pad values on the right.
+///
+/// Returns [`Error::BufferTooSmall`] if the decoded data is longer than the field.
+///
+/// # Examples
+///
+/// ```rust
+/// use bs58::decode::LeftAligned;
+///
+/// let mut field = [0xFF; 8];
+/// assert_eq!(5, bs58::decode("EUYUqQf").onto(LeftAligned(&mut field))?);
+/// assert_eq!(b"world\0\0\0", &field);
+///
+/// assert_eq!(
+///     bs58::decode::Error::BufferTooSmall,
+///     bs58::decode("EUYUqQf").onto(LeftAligned(&mut field[..4])).unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct LeftAligned<'a>(pub &'a mut [u8]);
+
+impl DecodeTarget for LeftAligned<'_> {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        let _ = max_len;
+        let len = f(self.0)?;
+        self.0[len..].fill(0);
+        Ok(len)
+    }
+}
+
 impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
     /// Setup decoder for the given string using the given alphabet.
     /// Preferably use [`bs58::decode`](crate::decode()) instead of this directly.
@@ -201,6 +586,27 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
             input,
             alpha,
             check: Check::Disabled,
+            #[cfg(feature = "check-core")]
+            check_range: (Bound::Unbounded, Bound::Unbounded),
+            #[cfg(feature = "check-core")]
+            trailing_version: false,
+            replace_target: false,
+            #[cfg(feature = "alloc")]
+            exact_prealloc: false,
+            padded: false,
+            prefix: &[],
+            delimiter: None,
+            crc32_footer: false,
+            expected_len: None,
+            expected_leading_zeros: None,
+            sanitize_unicode: false,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            sha256: crate::default_sha256,
+            #[cfg(feature = "check-core")]
+            sha256_incremental: crate::default_sha256_incremental,
+            #[cfg(feature = "percent-encoding")]
+            percent_decode: false,
+            hasher: PhantomData,
         }
     }
 
@@ -210,9 +616,32 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
             input,
             alpha: Alphabet::DEFAULT,
             check: Check::Disabled,
+            #[cfg(feature = "check-core")]
+            check_range: (Bound::Unbounded, Bound::Unbounded),
+            #[cfg(feature = "check-core")]
+            trailing_version: false,
+            replace_target: false,
+            #[cfg(feature = "alloc")]
+            exact_prealloc: false,
+            padded: false,
+            prefix: &[],
+            delimiter: None,
+            crc32_footer: false,
+            expected_len: None,
+            expected_leading_zeros: None,
+            sanitize_unicode: false,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            sha256: crate::default_sha256,
+            #[cfg(feature = "check-core")]
+            sha256_incremental: crate::default_sha256_incremental,
+            #[cfg(feature = "percent-encoding")]
+            percent_decode: false,
+            hasher: PhantomData,
         }
     }
+}
 
+impl<'a, I: AsRef<[u8]>, H> DecodeBuilder<'a, I, H> {
     /// Change the alphabet that will be used for decoding.
     ///
     /// # Examples
@@ -225,7 +654,7 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
     ///         .into_vec()?);
     /// # Ok::<(), bs58::decode::Error>(())
     /// ```
-    pub const fn with_alphabet(mut self, alpha: &'a Alphabet) -> DecodeBuilder<'a, I> {
+    pub const fn with_alphabet(mut self, alpha: &'a Alphabet) -> DecodeBuilder<'a, I, H> {
         self.alpha = alpha;
         self
     }
@@ -248,12 +677,220 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
     ///         .into_vec()?);
     /// # Ok::<(), bs58::decode::Error>(())
     /// ```
-    #[cfg(feature = "check")]
-    pub fn with_check(self, expected_ver: Option<u8>) -> DecodeBuilder<'a, I> {
-        let check = Check::Enabled(expected_ver);
+    #[cfg(feature = "check-core")]
+    pub fn with_check(self, expected_ver: Option<u8>) -> DecodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(expected_ver.into());
+        DecodeBuilder {
+            check,
+            trailing_version: false,
+            ..self
+        }
+    }
+
+    /// Expect and check checksum using the [Base58Check][] algorithm when
+    /// decoding, along with a multi-byte version prefix as used by formats
+    /// such as [BIP32][] extended keys.
+    ///
+    /// The version bytes are verified but not removed from the output. If they
+    /// don't match, [`Error::InvalidVersion`] reports the first differing byte.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    /// [BIP32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x2d, 0x31],
+    ///     bs58::decode("PWEu9GGN")
+    ///         .with_check_version_bytes(&[0x2d])
+    ///         .into_vec()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_check_version_bytes(self, expected_ver: &'a [u8]) -> DecodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(Version::Bytes(expected_ver));
+        DecodeBuilder {
+            check,
+            trailing_version: false,
+            ..self
+        }
+    }
+
+    /// Expect and check checksum using the [Base58Check][] algorithm when
+    /// decoding, for formats which append the version byte to the payload
+    /// rather than prepending it, i.e. `payload || version || checksum`.
+    ///
+    /// The version byte is verified but not removed from the output.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x31, 0x2d],
+    ///     bs58::decode("RVPoNSXu")
+    ///         .with_check_trailing_version(0x2d)
+    ///         .into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidVersion { ver: 0x2d, expected_ver: 0x31 },
+    ///     bs58::decode("RVPoNSXu")
+    ///         .with_check_trailing_version(0x31)
+    ///         .into_vec()
+    ///         .unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_check_trailing_version(self, expected_ver: u8) -> DecodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(Version::Byte(expected_ver));
+        DecodeBuilder {
+            check,
+            trailing_version: true,
+            ..self
+        }
+    }
+
+    /// Expect a [Base58Check][] checksum as a separate segment after `delimiter`, as encoded by
+    /// [`EncodeBuilder::with_check_delimited`](crate::encode::EncodeBuilder::with_check_delimited),
+    /// and verify it against the payload before it.
+    ///
+    /// The input is split at the first `delimiter`, returning [`Error::NoChecksum`] if there is
+    /// none or the checksum segment doesn't decode to exactly 4 bytes, and
+    /// [`Error::InvalidChecksum`] if it doesn't match. Only the payload is returned, and indexes
+    /// in errors refer to the whole input.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Panics
+    ///
+    /// If `delimiter` is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() -> Result<(), bs58::decode::Error> {
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode("he11owor1d.45W3Aw").with_check_delimited('.').into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidChecksum {
+    ///         checksum: [0x78, 0x66, 0xc8, 0x30],
+    ///         expected_checksum: [0x8c, 0xe8, 0x3e, 0x7a],
+    ///     },
+    ///     bs58::decode("he11owor1e.45W3Aw").with_check_delimited('.').into_vec().unwrap_err());
+    /// assert_eq!(
+    ///     bs58::decode::Error::NoChecksum,
+    ///     bs58::decode("he11owor1d").with_check_delimited('.').into_vec().unwrap_err());
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_check_delimited(self, delimiter: char) -> DecodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        assert!(delimiter.is_ascii(), "checksum delimiter must be ASCII");
+        let check = Check::Delimited(delimiter as u8);
         DecodeBuilder { check, ..self }
     }
 
+    /// Use the given SHA-256 implementation to verify checksums, instead of the default
+    /// implementation from the `sha2` crate.
+    ///
+    /// Without the `sha2` feature this must be called before any checked mode can be enabled.
+    /// See [`Sha256`] for more details and an example.
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+    pub fn with_sha256<S: Sha256>(self) -> DecodeBuilder<'a, I, S> {
+        DecodeBuilder {
+            input: self.input,
+            alpha: self.alpha,
+            check: self.check,
+            #[cfg(feature = "check-core")]
+            check_range: self.check_range,
+            #[cfg(feature = "check-core")]
+            trailing_version: self.trailing_version,
+            replace_target: self.replace_target,
+            #[cfg(feature = "alloc")]
+            exact_prealloc: self.exact_prealloc,
+            padded: self.padded,
+            prefix: self.prefix,
+            delimiter: self.delimiter,
+            crc32_footer: self.crc32_footer,
+            expected_len: self.expected_len,
+            expected_leading_zeros: self.expected_leading_zeros,
+            sanitize_unicode: self.sanitize_unicode,
+            sha256: S::digest,
+            #[cfg(feature = "check-core")]
+            sha256_incremental: S::digest_incremental,
+            #[cfg(feature = "percent-encoding")]
+            percent_decode: self.percent_decode,
+            hasher: PhantomData,
+        }
+    }
+
+    /// Expect and check a [Base58Check][] checksum that was computed over only part of the
+    /// payload, for layered formats that checksum a prefix or other sub-range of their data.
+    ///
+    /// The range indexes into the decoded payload, excluding the trailing checksum bytes. If it
+    /// is not within the payload [`Error::InvalidChecksumRange`] is returned. Any version set by
+    /// [`Self::with_check`] is kept, otherwise the version is not checked.
+    ///
+    /// The range only applies to Base58Check. A checksum scheme already configured with
+    /// [`Self::as_cb58`], [`Self::with_check_delimited`] or [`Self::with_check_fn`] is kept as is
+    /// and still checks the whole payload.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x2d, 0x31, 0xff],
+    ///     bs58::decode("2iLXBB8Tg2")
+    ///         .with_check(Some(0x2d))
+    ///         .with_check_over(..2)
+    ///         .into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidChecksumRange { start: 0, end: 4, len: 3 },
+    ///     bs58::decode("2iLXBB8Tg2")
+    ///         .with_check_over(..4)
+    ///         .into_vec()
+    ///         .unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_check_over(self, range: impl RangeBounds<usize>) -> DecodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = match self.check {
+            Check::Disabled => Check::Enabled(None.into()),
+            check => check,
+        };
+        let check_range = (range.start_bound().cloned(), range.end_bound().cloned());
+        DecodeBuilder {
+            check,
+            check_range,
+            ..self
+        }
+    }
+
     /// Expect and check checksum using the [CB58][] algorithm when
     /// decoding.
     ///
@@ -272,30 +909,1194 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
     ///         .into_vec()?);
     /// # Ok::<(), bs58::decode::Error>(())
     /// ```
-    #[cfg(feature = "cb58")]
-    pub fn as_cb58(self, expected_ver: Option<u8>) -> DecodeBuilder<'a, I> {
-        let check = Check::CB58(expected_ver);
+    #[cfg(feature = "cb58-core")]
+    pub fn as_cb58(self, expected_ver: Option<u8>) -> DecodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::CB58(expected_ver.into());
         DecodeBuilder { check, ..self }
     }
-    /// Decode into a new vector of bytes.
+
+    /// Expect and verify a trailing checksum calculated by the given function when decoding, the
+    /// counterpart to
+    /// [`EncodeBuilder::with_check_fn`](crate::encode::EncodeBuilder::with_check_fn).
     ///
-    /// See the documentation for [`bs58::decode`](crate::decode()) for an
-    /// explanation of the errors that may occur.
+    /// The decoded bytes must be `payload || checksum`, where `checksum` is the `N` bytes
+    /// written by `f(payload, checksum)`, only the payload is returned. Returns
+    /// [`Error::InvalidCheckFnChecksum`] if the checksum doesn't match or the decoded data is
+    /// shorter than it. Checksums are limited to 64 bytes, a longer `N` fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let xor = |payload: &[u8], checksum: &mut [u8]| {
+    ///     checksum[0] = payload.iter().fold(0, |acc, b| acc ^ b);
+    /// };
+    ///
+    /// let encoded = bs58::encode([0x04, 0x30, 0x5e, 0x2b]).with_check_fn::<1>(&xor).into_string();
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b],
+    ///     bs58::decode(&encoded).with_check_fn::<1>(&xor).into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidCheckFnChecksum,
+    ///     bs58::decode("he11owor1d").with_check_fn::<1>(&xor).into_vec().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn with_check_fn<const N: usize>(
+        self,
+        f: &'a dyn Fn(&[u8], &mut [u8]),
+    ) -> DecodeBuilder<'a, I, H> {
+        const { assert!(N <= MAX_CHECK_FN_LEN, "checksums are limited to 64 bytes") };
+        let check = Check::Custom { len: N, f };
+        DecodeBuilder { check, ..self }
+    }
+
+    /// [Percent-decode][] the input before decoding it as base58, for input taken from a URL.
+    ///
+    /// Any errors from base58 decoding will refer to indexes in the percent-decoded input.
+    ///
+    /// [Percent-decode]: https://url.spec.whatwg.org/#percent-decode
     ///
     /// # Examples
     ///
     /// ```rust
     /// assert_eq!(
     ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
-    ///     bs58::decode("he11owor1d").into_vec()?);
+    ///     bs58::decode("he%31%31owor1d")
+    ///         .percent_decode()
+    ///         .into_vec()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidPercentEncoding { index: 2 },
+    ///     bs58::decode("he%3").percent_decode().into_vec().unwrap_err());
+    /// ```
+    #[cfg(feature = "percent-encoding")]
+    pub fn percent_decode(self) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            percent_decode: true,
+            ..self
+        }
+    }
+
+    /// Replace any existing data in a resizable buffer passed to [`Self::onto`], rather than
+    /// appending to it.
+    ///
+    /// The buffer is cleared before decoding, so on error it will be left empty. This has no
+    /// effect on fixed size buffers, which are always written from the start.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = b"hello ".to_vec();
+    /// assert_eq!(5, bs58::decode("EUYUqQf").onto(&mut output)?);
+    /// assert_eq!(b"hello world", output.as_slice());
+    ///
+    /// let mut output = b"hello ".to_vec();
+    /// assert_eq!(5, bs58::decode("EUYUqQf").replace_target().onto(&mut output)?);
+    /// assert_eq!(b"world", output.as_slice());
     /// # Ok::<(), bs58::decode::Error>(())
     /// ```
+    pub fn replace_target(self) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            replace_target: true,
+            ..self
+        }
+    }
+
+    /// Decode into a temporary buffer first, so that a resizable buffer passed to [`Self::onto`]
+    /// or created by [`Self::into_vec`] is only extended by exactly the decoded length.
+    ///
+    /// Normally the buffer is extended by an upper bound on the decoded length, around 0.73 bytes
+    /// per input character, and truncated after decoding, which may leave unused capacity.
+    /// This costs an extra allocation and copy of the output, which is small compared to decoding
+    /// itself, so is worth enabling for large inputs whose result is kept around for a long time.
     ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let encoded = bs58::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).into_string();
+    /// let output = bs58::decode(&encoded).exact_prealloc().into_vec()?;
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], output);
+    /// assert_eq!(8, output.capacity());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
     #[cfg(feature = "alloc")]
-    pub fn into_vec(self) -> Result<Vec<u8>> {
-        let mut output = Vec::new();
-        self.onto(&mut output)?;
-        Ok(output)
+    pub fn exact_prealloc(self) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            exact_prealloc: true,
+            ..self
+        }
+    }
+
+    /// Expect the input to start with the given literal prefix, such as a human-readable
+    /// address type, and remove it before decoding.
+    ///
+    /// If the input doesn't start with the prefix [`Error::MissingPrefix`] is returned. The
+    /// indexes in other errors still refer to the full input including the prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode("addr_he11owor1d").strip_prefix("addr_").into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::MissingPrefix,
+    ///     bs58::decode("he11owor1d").strip_prefix("addr_").into_vec().unwrap_err());
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidCharacter { character: '0', index: 10 },
+    ///     bs58::decode("addr_he11o0").strip_prefix("addr_").into_vec().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn strip_prefix(self, prefix: &'a str) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            prefix: prefix.as_bytes(),
+            ..self
+        }
+    }
+
+    /// Expect the decoded data to be exactly `len` bytes long, such as the length stored alongside
+    /// the encoding from [`EncodeBuilder::into_indexed`](crate::encode::EncodeBuilder::into_indexed),
+    /// returning [`Error::WrongLength`] otherwise.
+    ///
+    /// This is the length of the data returned, so when a checksum is enabled it includes any
+    /// version bytes but not the checksum itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let (encoded, len) = bs58::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+    ///     .into_indexed();
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode(&encoded).expect_len(len).into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::WrongLength { expected: 7, actual: 8 },
+    ///     bs58::decode(&encoded).expect_len(7).into_vec().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn expect_len(self, len: usize) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            expected_len: Some(len),
+            ..self
+        }
+    }
+
+    /// Expect the decoded data to start with exactly `count` zero bytes, for fixed formats with a
+    /// zeroed region such as a version, returning [`Error::WrongLeadingZeros`] otherwise.
+    ///
+    /// Each leading zero byte is encoded as a leading zero character (`1` in the default alphabet),
+    /// so this is checked before decoding the rest of the input. As [`Self::padded`] removes all
+    /// leading zero characters, when combined with it the only count that can match is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0, 0, 0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode("11he11owor1d").expect_leading_zeros(2).into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::WrongLeadingZeros { expected: 2, actual: 1 },
+    ///     bs58::decode("1he11owor1d").expect_leading_zeros(2).into_vec().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn expect_leading_zeros(self, count: usize) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            expected_leading_zeros: Some(count),
+            ..self
+        }
+    }
+
+    /// Ignore any byte order marks and zero-width characters at the start or end of the input, as
+    /// are often included in text pasted from the clipboard.
+    ///
+    /// The ignored characters are U+FEFF (the UTF-8 BOM, `EF BB BF`), U+200B zero width space,
+    /// U+200C zero width non-joiner, U+200D zero width joiner and U+2060 word joiner. They are
+    /// removed before any prefix set by [`Self::strip_prefix`], while any other non-ASCII
+    /// characters, or these ones between base58 characters, are still reported as errors with
+    /// indexes into the full input. This also applies to the input scanned by
+    /// `into_vec_prefix` and `into_vec_lossy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode("\u{feff}he11owor1d\u{200b}").sanitize_unicode().into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::NonAsciiCharacter { index: 5 },
+    ///     bs58::decode("\u{feff}he\u{200b}11owor1d").sanitize_unicode().into_vec().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn sanitize_unicode(self) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            sanitize_unicode: true,
+            ..self
+        }
+    }
+
+    /// Only decode the input up to the first `delimiter` byte, for base58 embedded in a larger
+    /// binary framing. The delimiter and anything after it are ignored, except by
+    /// [`Self::with_crc32_footer`].
+    ///
+    /// With [`Self::into_vec_prefix`] the returned length includes the delimiter, and the footer
+    /// if [`Self::with_crc32_footer`] is used, so parsing can continue after the frame.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = b"he11owor1d\0trailing data";
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode(input).until_delimiter(0).into_vec()?);
+    ///
+    /// let (_, consumed) = bs58::decode(input).until_delimiter(0).into_vec_prefix()?;
+    /// assert_eq!(b"trailing data", &input[consumed..]);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn until_delimiter(self, delimiter: u8) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            delimiter: Some(delimiter),
+            ..self
+        }
+    }
+
+    /// Expect the input to be framed as `[base58][delimiter][crc32]`, verifying the CRC-32 (as
+    /// used by zlib and PNG) of the base58 characters against the 4 byte big-endian footer after
+    /// the delimiter before decoding them.
+    ///
+    /// This implies [`Self::until_delimiter`]. If there is no delimiter or the footer is shorter
+    /// than 4 bytes [`Error::InputTooShort`] is returned, if the CRC-32 doesn't match
+    /// [`Error::InvalidCrc32`] is returned. Any bytes after the footer are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = b"he11owor1d\0\x7a\xdf\x6d\xd4";
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode(input).with_crc32_footer(0).into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidCrc32 { crc: 0x7adf6dd4, expected_crc: 0x7adf6dd5 },
+    ///     bs58::decode(b"he11owor1d\0\x7a\xdf\x6d\xd5")
+    ///         .with_crc32_footer(0)
+    ///         .into_vec()
+    ///         .unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn with_crc32_footer(self, delimiter: u8) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            delimiter: Some(delimiter),
+            crc32_footer: true,
+            ..self
+        }
+    }
+
+    /// Strip padding added by
+    /// [`EncodeBuilder::padded_to`](crate::encode::EncodeBuilder::padded_to) when decoding.
+    ///
+    /// As padding is indistinguishable from leading zero characters this strips all of them, so
+    /// any leading zero bytes of the original data (including a zero version byte when using
+    /// checksums) are not returned. If they're needed, the original length must be stored
+    /// separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode("11he11owor1d").padded().into_vec()?);
+    ///
+    /// assert_eq!(
+    ///     vec![0x61],
+    ///     bs58::decode("12g").padded().into_vec()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn padded(self) -> DecodeBuilder<'a, I, H> {
+        DecodeBuilder {
+            padded: true,
+            ..self
+        }
+    }
+
+    /// Decode into a new vector of bytes.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bs58::decode("he11owor1d").into_vec()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.onto(&mut output)?;
+        Ok(output)
+    }
+
+    /// Decode into a new vector holding at most the first `limit` bytes, for previewing large
+    /// decoded blobs.
+    ///
+    /// This does **not** save any decoding work: the whole input is still decoded and
+    /// validated, and only the returned vector is truncated (and shrunk, so a long-lived preview
+    /// doesn't keep the full allocation alive). It is equivalent to `into_vec()` followed by
+    /// [`Vec::truncate`].
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(vec![0x04, 0x30, 0x5e], bs58::decode("he11owor1d").into_vec_limited(3)?);
+    /// assert_eq!(vec![0x61], bs58::decode("2g").into_vec_limited(3)?);
+    ///
+    /// // Errors anywhere in the input are still reported
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidCharacter { character: 'l', index: 2 },
+    ///     bs58::decode("hello").into_vec_limited(1).unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_limited(self, limit: usize) -> Result<Vec<u8>> {
+        // Base58 encodes the data as a single big number (after the leading zero bytes, which map
+        // one-to-one to leading zero characters), so the most significant byte depends on every
+        // character of the input: each one multiplies the value decoded so far by 58, carrying
+        // into all of its bytes. Apart from that zero prefix there is no way to produce the first
+        // bytes without decoding the whole input, and skipping the rest would also skip
+        // validating it, so just decode everything and truncate.
+        let mut output = self.into_vec()?;
+        output.truncate(limit);
+        output.shrink_to_fit();
+        Ok(output)
+    }
+
+    /// Decode into a new reference-counted slice, for keys that are decoded once and shared
+    /// between threads.
+    ///
+    /// Data of up to 128 bytes, such as keys, is decoded on the stack and copied straight into
+    /// the [`Arc`](alloc::sync::Arc) with a single allocation. Longer data is decoded into a
+    /// vector first.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let key = bs58::decode("he11owor1d").into_arc()?;
+    /// let shared = std::sync::Arc::clone(&key);
+    /// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], *shared);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub fn into_arc(self) -> Result<alloc::sync::Arc<[u8]>> {
+        let mut output = [0; 128];
+        match self.decode_onto(self.input.as_ref(), &mut output[..]) {
+            Ok(len) => Ok(output[..len].into()),
+            Err(Error::BufferTooSmall) => self.into_vec().map(Into::into),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Decode into a new boxed slice of exactly the decoded length, with no unused capacity, for
+    /// decoded values that are kept around for a long time.
+    ///
+    /// The exact decoded length depends on the value, not just the input length, so can only be
+    /// found by decoding. Data of up to 128 bytes is decoded on the stack first, longer data into
+    /// a temporary vector as with [`Self::exact_prealloc`], and then copied into a single
+    /// allocation of exactly the decoded length.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let decoded = bs58::decode("he11owor1d").into_exact_boxed_slice()?;
+    /// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], *decoded);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_exact_boxed_slice(self) -> Result<alloc::boxed::Box<[u8]>> {
+        let mut output = [0; 128];
+        match self.decode_onto(self.input.as_ref(), &mut output[..]) {
+            Ok(len) => Ok(output[..len].into()),
+            Err(Error::BufferTooSmall) => {
+                self.exact_prealloc().into_vec().map(Vec::into_boxed_slice)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Decode the longest prefix of the input made up of characters from the alphabet into a new
+    /// vector of bytes, for protocols where the base58 data is directly followed by other data.
+    ///
+    /// Returns the decoded bytes along with the number of bytes of input that were consumed
+    /// (including any prefix given to [`Self::strip_prefix`]), so the caller can continue parsing
+    /// after them. Any checksum is verified over only the consumed characters.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = "he11owor1d:rest";
+    /// let (decoded, consumed) = bs58::decode(input).into_vec_prefix()?;
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decoded);
+    /// assert_eq!(":rest", &input[consumed..]);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_prefix(self) -> Result<(Vec<u8>, usize)> {
+        let input = self.input.as_ref();
+        if self.delimiter.is_some() {
+            let (_, consumed) = self.frame(input)?;
+            let mut output = Vec::new();
+            self.decode_onto(input, &mut output)?;
+            return Ok((output, consumed));
+        }
+
+        let start = if self.sanitize_unicode {
+            sanitize_unicode(input).1
+        } else {
+            0
+        };
+        let start = if input[start..].starts_with(self.prefix) {
+            start + self.prefix.len()
+        } else {
+            start
+        };
+        let len = input[start..]
+            .iter()
+            .take_while(|c| **c < 128 && self.alpha.decode[**c as usize] != 0xFF)
+            .count();
+
+        let mut output = Vec::new();
+        self.decode_onto(&input[..start + len], &mut output)?;
+        Ok((output, start + len))
+    }
+
+    /// Decode into a new vector of bytes with the first of the built-in alphabets that the input
+    /// is valid for, returning the alphabet that was used along with the decoded bytes.
+    ///
+    /// The alphabets are tried in the order [`Alphabet::BITCOIN`], [`Alphabet::RIPPLE`],
+    /// [`Alphabet::FLICKR`], [`Alphabet::GMP`] ([`Alphabet::MONERO`] is the same as
+    /// [`Alphabet::BITCOIN`] so is never detected). Any alphabet set by [`Self::with_alphabet`] is
+    /// ignored.
+    ///
+    /// Other than [`Alphabet::GMP`] the built-in alphabets all contain the same characters in
+    /// different orders, so without further validation most valid input is decoded with
+    /// [`Alphabet::BITCOIN`]. Detection is only reliable with a checksum enabled, e.g. by
+    /// [`Self::with_check`], which will only match the alphabet the input was encoded with.
+    ///
+    /// If the input isn't valid for any of the alphabets, the error from decoding with
+    /// [`Alphabet::BITCOIN`] is returned. See the documentation for
+    /// [`bs58::decode`](crate::decode()) for an explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let (decoded, alphabet) = bs58::decode("he11owor1d").into_vec_detecting_alphabet()?;
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decoded);
+    /// assert_eq!(bs58::Alphabet::BITCOIN, alphabet);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_detecting_alphabet(mut self) -> Result<(Vec<u8>, &'static Alphabet)> {
+        let mut first_error = None;
+        for alpha in [
+            Alphabet::BITCOIN,
+            Alphabet::RIPPLE,
+            Alphabet::FLICKR,
+            Alphabet::GMP,
+        ] {
+            self.alpha = alpha;
+            let mut output = Vec::new();
+            match self.decode_onto(self.input.as_ref(), &mut output) {
+                Ok(_) => return Ok((output, alpha)),
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+        Err(first_error.unwrap())
+    }
+
+    /// Decode into a new vector of bytes, verifying any checksum enabled by [`Self::with_check`] or
+    /// [`Self::as_cb58`] but returning the decoded payload along with whether the checksum was
+    /// valid, rather than failing on a mismatch.
+    ///
+    /// This allows showing what an input decodes to even when its checksum is wrong. The payload
+    /// is the same as a successful decode would return, with every other option still applied.
+    /// Errors other than a checksum mismatch are still returned, including a mismatched version
+    /// when the checksum is valid. A mismatch is [`Error::InvalidChecksum`], or
+    /// [`Error::InvalidCheckFnChecksum`] for a checksum set by [`Self::with_check_fn`] when there
+    /// are enough bytes for it. If no checksum is enabled the result is always considered valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() -> Result<(), bs58::decode::Error> {
+    /// assert_eq!(
+    ///     (vec![0x2d, 0x31], true),
+    ///     bs58::decode("PWEu9GGN").with_check(None).into_vec_lenient()?);
+    /// assert_eq!(
+    ///     (vec![0x2d, 0x31], false),
+    ///     bs58::decode("PWEu9GGP").with_check(None).into_vec_lenient()?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_lenient(self) -> Result<(Vec<u8>, bool)> {
+        let mut output = Vec::new();
+        let (_, checksum_valid) =
+            self.decode_onto_verifying(self.input.as_ref(), &mut output, true)?;
+        Ok((output, checksum_valid))
+    }
+
+    /// Decode into a new vector of bytes and verify it against a checksum transmitted separately,
+    /// for protocols with an integrity field outside the encoded data.
+    ///
+    /// Unlike [`Self::with_check`] and [`Self::as_cb58`] the input doesn't contain the checksum,
+    /// the whole decoded data is the payload that `checksum` is calculated over with `scheme`.
+    /// Any checksum configured on this builder is ignored, but the SHA-256 implementation from
+    /// [`Self::with_sha256`] is used.
+    ///
+    /// Returns [`Error::InvalidChecksum`] with `checksum` set to the given checksum if it doesn't
+    /// match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() -> Result<(), bs58::decode::Error> {
+    /// use bs58::decode::CheckScheme;
+    ///
+    /// // "PWEu9GGN" is the Base58Check encoding of [0x2d, 0x31] with checksum 0x11467677
+    /// let checksum = [0x11, 0x46, 0x76, 0x77];
+    /// assert_eq!(
+    ///     vec![0x2d, 0x31],
+    ///     bs58::decode("4SU").verify_external_checksum(checksum, CheckScheme::Check)?);
+    ///
+    /// assert!(matches!(
+    ///     bs58::decode("4SV").verify_external_checksum(checksum, CheckScheme::Check),
+    ///     Err(bs58::decode::Error::InvalidChecksum { .. })));
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(all(any(feature = "check-core", feature = "cb58-core"), feature = "alloc"))]
+    pub fn verify_external_checksum(
+        self,
+        checksum: [u8; CHECKSUM_LEN],
+        scheme: CheckScheme,
+    ) -> Result<Vec<u8>>
+    where
+        H: Sha256,
+    {
+        let sha256 = self.sha256;
+        let payload = DecodeBuilder {
+            check: Check::Disabled,
+            ..self
+        }
+        .into_vec()?;
+
+        let expected_checksum = match scheme {
+            #[cfg(feature = "check-core")]
+            CheckScheme::Check => crate::encode::check_checksum(&payload, &[], sha256),
+            #[cfg(feature = "cb58-core")]
+            CheckScheme::CB58 => crate::encode::cb58_checksum(&payload, &[], sha256),
+        };
+        if checksum != expected_checksum {
+            return Err(Error::InvalidChecksum {
+                checksum,
+                expected_checksum,
+            });
+        }
+        Ok(payload)
+    }
+
+    /// Decode into a new vector of bytes, along with whether the input was exactly the canonical
+    /// encoding of the decoded data, i.e. re-encoding it gives back the same input.
+    ///
+    /// This allows accepting but flagging non-canonical input for monitoring. The comparison is
+    /// against the whole input, so anything accepted by the decoder but not produced by the
+    /// encoder, such as aliases from [`Alphabet::builder`], a prefix from
+    /// [`Self::strip_prefix`] or padding from [`Self::padded`], makes it non-canonical. Any
+    /// checksum is verified as usual and is part of the compared encoding.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     (vec![0, 0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], true),
+    ///     bs58::decode("1he11owor1d").into_vec_canonical_flag()?);
+    ///
+    /// let alpha = bs58::Alphabet::builder(&bs58::Alphabet::BITCOIN.valid_chars())
+    ///     .alias(b'0', b'1')
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     (vec![0, 0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], false),
+    ///     bs58::decode("0he11owor1d").with_alphabet(&alpha).into_vec_canonical_flag()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_canonical_flag(self) -> Result<(Vec<u8>, bool)> {
+        let mut output = Vec::new();
+        self.decode_onto(self.input.as_ref(), &mut output)?;
+
+        // Canonicality is a property of the raw bytes, including any checksum
+        let checked = !matches!(self.check, Check::Disabled);
+        let unchecked = DecodeBuilder {
+            check: Check::Disabled,
+            expected_len: None,
+            ..self
+        };
+        let input = unchecked.input.as_ref();
+        let mut raw = Vec::new();
+        if checked {
+            unchecked.decode_onto(input, &mut raw)?;
+        }
+        let encoded = crate::encode(if checked { &raw } else { &output })
+            .with_alphabet(unchecked.alpha)
+            .into_vec();
+        Ok((output, encoded == input))
+    }
+
+    /// Decode into a new vector of bytes, also returning the original input if it was valid, for
+    /// storing both the encoded and decoded forms.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let (encoded, decoded) = bs58::decode("he11owor1d").into_validated()?;
+    /// assert_eq!("he11owor1d", encoded);
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decoded);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_validated(self) -> Result<(I, Vec<u8>)> {
+        let mut output = Vec::new();
+        self.decode_onto(self.input.as_ref(), &mut output)?;
+        Ok((self.input, output))
+    }
+
+    /// Decode into a new string containing the lowercase hex representation of the decoded
+    /// bytes, e.g. for displaying them.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!("04305e2b2473f058", bs58::decode("he11owor1d").into_hex_string()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_hex_string(self) -> Result<String> {
+        Ok(crate::hex::encode(&self.into_vec()?, false))
+    }
+
+    /// Decode into a new string containing the uppercase hex representation of the decoded
+    /// bytes.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!("04305E2B2473F058", bs58::decode("he11owor1d").into_upper_hex_string()?);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_upper_hex_string(self) -> Result<String> {
+        Ok(crate::hex::encode(&self.into_vec()?, true))
+    }
+
+    /// Decode, passing each decoded byte in order to the given closure, e.g. to feed the decoded
+    /// data directly into a hasher.
+    ///
+    /// The closure is only called once the whole input has been successfully decoded (and any
+    /// checksum verified), so no bytes are seen if an error occurs.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut sum = 0u32;
+    /// bs58::decode("he11owor1d").for_each_byte(|b| sum += u32::from(b))?;
+    /// assert_eq!(0x04 + 0x30 + 0x5e + 0x2b + 0x24 + 0x73 + 0xf0 + 0x58, sum);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn for_each_byte(self, f: impl FnMut(u8)) -> Result<()> {
+        self.into_vec()?.into_iter().for_each(f);
+        Ok(())
+    }
+
+    /// Decode into a new vector of bytes, recovering as much as possible if an error occurs.
+    ///
+    /// On success this is the same as [`Self::into_vec`]. On failure, the returned vector instead
+    /// contains the plain base58 decoding of the longest prefix of the input that consists only
+    /// of characters from the alphabet, without any checksum verification or version stripping,
+    /// along with the error that stopped the full decode. Because base58 encodes a single large
+    /// number, this partial output is not necessarily a prefix of what the full input would have
+    /// decoded to.
+    ///
+    /// The input is prepared the same way as for a full decode first, so options such as
+    /// [`Self::sanitize_unicode`], [`Self::strip_prefix`] and [`Self::padded`] still apply, and
+    /// the prefix stops at any checksum delimiter. If preparing the input fails, for example
+    /// because the prefix is missing, the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     (vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], None),
+    ///     bs58::decode("he11owor1d").into_vec_lossy());
+    ///
+    /// assert_eq!(
+    ///     (
+    ///         vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///         Some(bs58::decode::Error::InvalidCharacter { character: '!', index: 10 }),
+    ///     ),
+    ///     bs58::decode("he11owor1d!!").into_vec_lossy());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_lossy(self) -> (Vec<u8>, Option<Error>) {
+        let mut output = Vec::new();
+        match self.decode_onto(self.input.as_ref(), &mut output) {
+            Ok(_) => (output, None),
+            Err(err) => {
+                output.clear();
+                let delimiter = match self.check {
+                    #[cfg(feature = "check-core")]
+                    Check::Delimited(delimiter) => Some(delimiter),
+                    _ => None,
+                };
+                let _ = self.with_normalized(self.input.as_ref(), |input, _| {
+                    let valid = input
+                        .iter()
+                        .take_while(|&&c| {
+                            c < 128 && self.alpha.decode[c as usize] != 0xFF && Some(c) != delimiter
+                        })
+                        .count();
+                    // A resizable buffer and only valid characters means this cannot fail
+                    output.decode_with(valid, |output| {
+                        decode_into(&input[..valid], output, self.alpha)
+                    })
+                });
+                (output, Some(err))
+            }
+        }
+    }
+
+    /// Scan the whole input for characters that aren't part of the alphabet, returning an
+    /// [`Error::InvalidCharacter`] or [`Error::NonAsciiCharacter`] for each one, so that a UI can
+    /// highlight all of them at once.
+    ///
+    /// This doesn't decode the input, so returns an empty vector for any input that only
+    /// contains valid characters, even if decoding it would fail for another reason such as a
+    /// checksum mismatch. The input is prepared the same way as for decoding, so characters
+    /// skipped by [`Self::strip_prefix`], [`Self::sanitize_unicode`] and similar aren't reported
+    /// and indexes refer to the original input; if preparing it fails, such as for a missing
+    /// prefix, that error is the only one returned. A multi-byte character is reported once, at
+    /// the index of its first byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert!(bs58::decode("he11owor1d").collect_errors().is_empty());
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         bs58::decode::Error::InvalidCharacter { character: 'l', index: 2 },
+    ///         bs58::decode::Error::InvalidCharacter { character: 'l', index: 3 },
+    ///         bs58::decode::Error::NonAsciiCharacter { index: 5 },
+    ///         bs58::decode::Error::InvalidCharacter { character: '0', index: 7 },
+    ///     ],
+    ///     bs58::decode("hello\u{e9}0").collect_errors());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn collect_errors(self) -> Vec<Error> {
+        let delimiter = match self.check {
+            #[cfg(feature = "check-core")]
+            Check::Delimited(delimiter) => Some(delimiter),
+            _ => None,
+        };
+        let mut errors = Vec::new();
+        let normalized = self.with_normalized(self.input.as_ref(), |input, offset| {
+            // Only the first delimiter separates the checksum, any others are invalid
+            let split = delimiter.and_then(|delimiter| input.iter().position(|c| *c == delimiter));
+            for (i, &c) in input.iter().enumerate() {
+                let index = i + offset;
+                if c > 127 {
+                    // Skip UTF-8 continuation bytes of a character that has already been reported
+                    let continuation = c & 0xC0 == 0x80;
+                    if !(continuation && i > 0 && input[i - 1] > 127) {
+                        errors.push(Error::NonAsciiCharacter { index });
+                    }
+                } else if self.alpha.decode[c as usize] == 0xFF && Some(i) != split {
+                    errors.push(Error::InvalidCharacter {
+                        character: c as char,
+                        index,
+                    });
+                }
+            }
+            Ok(())
+        });
+        if let Err(err) = normalized {
+            errors.push(err);
+        }
+        errors
+    }
+
+    /// Decode into a new vector of bytes, skipping any ASCII whitespace in the input, for
+    /// sanitizing pasted data.
+    ///
+    /// Returns the decoded bytes along with the (byte) indexes in the input that whitespace was
+    /// found at, so that callers can warn about it. Indexes in errors refer to the original input,
+    /// including whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     (vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], vec![0, 6, 12]),
+    ///     bs58::decode(" he11o wor1d\n").into_vec_ignoring_whitespace()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InvalidCharacter { character: 'l', index: 4 },
+    ///     bs58::decode("he 1l").into_vec_ignoring_whitespace().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_vec_ignoring_whitespace(self) -> Result<(Vec<u8>, Vec<usize>)> {
+        let input = self.input.as_ref();
+        let whitespace: Vec<usize> = (0..input.len())
+            .filter(|&i| input[i].is_ascii_whitespace())
+            .collect();
+        let trimmed: Vec<u8> = input
+            .iter()
+            .copied()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+
+        let original_index = |mut index| {
+            for &i in &whitespace {
+                if i > index {
+                    break;
+                }
+                index += 1;
+            }
+            index
+        };
+
+        let mut output = Vec::new();
+        match self.decode_onto(&trimmed, &mut output) {
+            Ok(_) => Ok((output, whitespace)),
+            Err(Error::InvalidCharacter { character, index }) => Err(Error::InvalidCharacter {
+                character,
+                index: original_index(index),
+            }),
+            Err(Error::NonAsciiCharacter { index }) => Err(Error::NonAsciiCharacter {
+                index: original_index(index),
+            }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Decode a record written by
+    /// [`EncodeBuilder::into_length_prefixed_vec`](crate::encode::EncodeBuilder::into_length_prefixed_vec),
+    /// a big-endian `u32` length followed by that many base58 characters, into a new vector of
+    /// bytes.
+    ///
+    /// Returns the decoded bytes along with the number of bytes of the input consumed, any
+    /// following data is ignored. Errors for invalid characters refer to indexes after the length
+    /// prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = b"\0\0\0\x0ahe11owor1d\0\0\0\x02?!";
+    /// let (decoded, consumed) = bs58::decode(input).into_length_prefixed_vec()?;
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decoded);
+    /// assert_eq!(14, consumed);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::InputTooShort { expected: 6, actual: 5 },
+    ///     bs58::decode(&input[14..19]).into_length_prefixed_vec().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_length_prefixed_vec(self) -> Result<(Vec<u8>, usize)> {
+        let input = self.input.as_ref();
+        let too_short = |expected| Error::InputTooShort {
+            expected,
+            actual: input.len(),
+        };
+        let (prefix, rest) = input.split_first_chunk::<4>().ok_or(too_short(4))?;
+        let len = u32::from_be_bytes(*prefix) as usize;
+        let encoded = rest.get(..len).ok_or(too_short(len.saturating_add(4)))?;
+        let mut output = Vec::new();
+        self.decode_onto(encoded, &mut output)?;
+        Ok((output, len + 4))
+    }
+
+    /// Decode as [Base58Check][] with a network byte followed by a version byte as the prefix,
+    /// i.e. `network || version || payload || checksum`, into the network, version and a new
+    /// vector of the payload bytes.
+    ///
+    /// This is the counterpart to
+    /// [`EncodeBuilder::with_network_version`](crate::encode::EncodeBuilder::with_network_version),
+    /// the checksum is verified over the whole prefix and payload, replacing any check configured
+    /// on this builder. Returns [`Error::NoNetworkVersion`] if there are fewer than two bytes
+    /// before the checksum.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let (network, version, payload) =
+    ///     bs58::decode("6daR2mjseBoDRR4Sf").into_check_network_version()?;
+    /// assert_eq!((1, 42), (network, version));
+    /// assert_eq!(vec![0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78], payload);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::NoNetworkVersion,
+    ///     bs58::decode("BXvDbH").into_check_network_version().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(all(feature = "check-core", feature = "alloc"))]
+    pub fn into_check_network_version(self) -> Result<(u8, u8, Vec<u8>)>
+    where
+        H: Sha256,
+    {
+        let decoded = self.with_check(None).into_vec()?;
+        match *decoded {
+            [network, version, ref payload @ ..] => Ok((network, version, payload.to_vec())),
+            _ => Err(Error::NoNetworkVersion),
+        }
+    }
+
+    /// Decode as [Base58Check][] and return only the version byte, without verifying the checksum,
+    /// for quickly categorizing addresses (mainnet, testnet, P2SH, etc.).
+    ///
+    /// The version is always read from the first decoded byte, and any check configured on this
+    /// builder is ignored. As the checksum is not verified a corrupted input may still return a
+    /// version, so this must not be used to validate addresses. Returns [`Error::NoChecksum`] if
+    /// there are not enough bytes for a version byte and a checksum.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(0x00, bs58::decode("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").check_version_only()?);
+    /// assert_eq!(0x05, bs58::decode("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").check_version_only()?);
+    /// assert_eq!(0x6f, bs58::decode("mrS8eVKXguwufwvsVe9GtgGb7fif9UQeAu").check_version_only()?);
+    ///
+    /// // The checksum isn't verified
+    /// assert_eq!(0x00, bs58::decode("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3").check_version_only()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::NoChecksum,
+    ///     bs58::decode("3EFU7m").check_version_only().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(all(feature = "check-core", feature = "alloc"))]
+    pub fn check_version_only(self) -> Result<u8> {
+        let unchecked = DecodeBuilder {
+            check: Check::Disabled,
+            ..self
+        };
+        let decoded = unchecked.into_vec()?;
+        if decoded.len() <= CHECKSUM_LEN {
+            return Err(Error::NoChecksum);
+        }
+        Ok(decoded[0])
+    }
+
+    /// Decode into a new [`Cursor`](std::io::Cursor) over a vector of bytes, for use with parsers
+    /// expecting [`Read`](std::io::Read) + [`Seek`](std::io::Seek).
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Read, Seek, SeekFrom};
+    ///
+    /// let mut cursor = bs58::decode("he11owor1d").into_cursor()?;
+    /// cursor.seek(SeekFrom::Start(4))?;
+    /// let mut tail = Vec::new();
+    /// cursor.read_to_end(&mut tail)?;
+    /// assert_eq!(vec![0x24, 0x73, 0xf0, 0x58], tail);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn into_cursor(self) -> Result<std::io::Cursor<Vec<u8>>> {
+        self.into_vec().map(std::io::Cursor::new)
+    }
+
+    /// Decode into a new array of at most `N` bytes, along with the length written to it, without
+    /// allocating.
+    ///
+    /// Unlike [`into_array_const`](DecodeBuilder::into_array_const) the decoded data may be shorter
+    /// than `N`, and the returned length distinguishes decoded zero bytes from unused space at the
+    /// end of the array.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let (output, len) = bs58::decode("EUYUqQf").into_inline::<8>()?;
+    /// assert_eq!(b"world", &output[..len]);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::BufferTooSmall,
+    ///     bs58::decode("EUYUqQf").into_inline::<4>().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn into_inline<const N: usize>(self) -> Result<([u8; N], usize)> {
+        let mut output = [0; N];
+        let len = self.onto(&mut output)?;
+        Ok((output, len))
+    }
+
+    /// Decode into a `u64`, interpreting the decoded data as a big-endian integer, for compact
+    /// numeric IDs.
+    ///
+    /// Leading zero characters (`1` in the default alphabet) decode to leading zero bytes, which
+    /// don't change the value but do count towards its length, so `"1"` and `"11"` both decode
+    /// to `0`, while a string that decodes to more than 8 bytes returns
+    /// [`Error::IntegerOverflow`] even if the value itself would fit. When a checksum is enabled
+    /// this applies to the payload after the checksum is removed.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the other errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(0x04305e2b2473f058, bs58::decode("he11owor1d").into_u64()?);
+    /// assert_eq!(57, bs58::decode("z").into_u64()?);
+    /// assert_eq!(57, bs58::decode("1z").into_u64()?);
+    /// assert_eq!(0, bs58::decode("").into_u64()?);
+    /// assert_eq!(u64::MAX, bs58::decode("jpXCZedGfVQ").into_u64()?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::IntegerOverflow,
+    ///     bs58::decode("jpXCZedGfVR").into_u64().unwrap_err());
+    /// assert_eq!(
+    ///     bs58::decode::Error::IntegerOverflow,
+    ///     bs58::decode("111111111").into_u64().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn into_u64(self) -> Result<u64> {
+        self.into_int(Endianness::Big)
+    }
+
+    /// Decode into an integer, interpreting the decoded data in the given byte order, as the
+    /// inverse of [`bs58::encode_int`](crate::encode_int).
+    ///
+    /// **Use [`Endianness::Big`] unless you know otherwise**; it is the
+    /// [default](Endianness::default), matches the Bitcoin convention and makes this equivalent
+    /// to [`into_u64`](Self::into_u64) for `u64`. The word size of `T` sets the maximum decoded
+    /// length, which includes leading zero bytes as for `into_u64`; with
+    /// [`Endianness::Little`] those leading zero bytes are the low-order bytes, so `"1z"`
+    /// decodes to `57 << 8`.
+    ///
+    /// See the documentation for [`bs58::decode`](crate::decode()) for an
+    /// explanation of the other errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bs58::int::Endianness;
+    ///
+    /// assert_eq!(57_u8, bs58::decode("z").into_int(Endianness::Big)?);
+    /// assert_eq!(57_u16, bs58::decode("1z").into_int(Endianness::Big)?);
+    /// assert_eq!(57_u16 << 8, bs58::decode("1z").into_int(Endianness::Little)?);
+    /// assert_eq!(u128::MAX, bs58::decode("YcVfxkQb6JRzqk5kF2tNLv").into_int(Endianness::Big)?);
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::IntegerOverflow,
+    ///     bs58::decode("1z").into_int::<u8>(Endianness::Little).unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn into_int<T: Int>(self, endianness: Endianness) -> Result<T> {
+        // Any checksum is decoded into the output along with the payload before being removed, so
+        // leave room for the longest one and check the payload length afterwards
+        let (output, len) =
+            self.into_inline::<{ 16 + MAX_CHECK_FN_LEN }>()
+                .map_err(|e| match e {
+                    Error::BufferTooSmall => Error::IntegerOverflow,
+                    e => e,
+                })?;
+        if len > T::BYTES {
+            return Err(Error::IntegerOverflow);
+        }
+        Ok(crate::int::from_bytes(&output[..len], endianness))
+    }
+
+    /// Decode into a [`NonZeroU64`](core::num::NonZeroU64), as [`into_u64`](Self::into_u64)
+    /// does, for IDs where zero is reserved.
+    ///
+    /// Returns [`Error::IntegerZero`] if the decoded value is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(57, bs58::decode("z").into_nonzero_u64()?.get());
+    ///
+    /// assert_eq!(
+    ///     bs58::decode::Error::IntegerZero,
+    ///     bs58::decode("11").into_nonzero_u64().unwrap_err());
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    pub fn into_nonzero_u64(self) -> Result<core::num::NonZeroU64> {
+        core::num::NonZeroU64::new(self.into_u64()?).ok_or(Error::IntegerZero)
     }
 
     /// Decode into the given buffer.
@@ -303,7 +2104,7 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
     /// Returns the length written into the buffer.
     ///
     /// If the buffer is resizeable it will be extended and the new data will be written to the end
-    /// of it.
+    /// of it, unless [`Self::replace_target`] is used to replace any existing data.
     ///
     /// If the buffer is not resizeable bytes will be written from the beginning and bytes after
     /// the final encoded byte will not be touched.
@@ -330,28 +2131,127 @@ impl<'a, I: AsRef<[u8]>> DecodeBuilder<'a, I> {
     /// assert_eq!(b"world ", output.as_ref());
     /// # Ok::<(), bs58::decode::Error>(())
     /// ```
-    pub fn onto(self, mut output: impl DecodeTarget) -> Result<usize> {
-        let max_decoded_len = self.input.as_ref().len();
-        match self.check {
-            Check::Disabled => output.decode_with(max_decoded_len, |output| {
-                decode_into(self.input.as_ref(), output, self.alpha)
-            }),
-            #[cfg(feature = "check")]
-            Check::Enabled(expected_ver) => output.decode_with(max_decoded_len, |output| {
-                decode_check_into(self.input.as_ref(), output, self.alpha, expected_ver)
-            }),
-            #[cfg(feature = "cb58")]
-            Check::CB58(expected_ver) => output.decode_with(max_decoded_len, |output| {
-                decode_cb58_into(self.input.as_ref(), output, self.alpha, expected_ver)
-            }),
+    pub fn onto(self, output: impl DecodeTarget) -> Result<usize> {
+        self.decode_onto(self.input.as_ref(), output)
+    }
+
+    fn decode_onto(&self, input: &[u8], output: impl DecodeTarget) -> Result<usize> {
+        let (len, _) = self.decode_onto_verifying(input, output, false)?;
+        Ok(len)
+    }
+
+    /// Decode onto `output`, returning the decoded length along with whether the checksum was
+    /// valid. Unless `lenient` is set a checksum mismatch is returned as an error instead.
+    fn decode_onto_verifying(
+        &self,
+        input: &[u8],
+        mut output: impl DecodeTarget,
+        lenient: bool,
+    ) -> Result<(usize, bool)> {
+        if self.replace_target {
+            output.clear();
+        }
+
+        #[cfg(feature = "alloc")]
+        if self.exact_prealloc {
+            return self.decode_onto_target(input, ExactLen(output), lenient);
         }
+
+        self.decode_onto_target(input, output, lenient)
+    }
+
+    fn decode_onto_target(
+        &self,
+        input: &[u8],
+        mut output: impl DecodeTarget,
+        lenient: bool,
+    ) -> Result<(usize, bool)> {
+        self.with_normalized(input, |input, offset| {
+            if let Some(expected) = self.expected_leading_zeros {
+                let actual = input.iter().take_while(|c| self.alpha.is_zero(**c)).count();
+                if actual != expected {
+                    return Err(Error::WrongLeadingZeros { expected, actual });
+                }
+            }
+
+            let mut checksum_valid = true;
+            let mut verify = |decoded: Result<(usize, Result<()>)>| {
+                let (actual, checksum) = decoded?;
+                match checksum {
+                    Err(_) if lenient => checksum_valid = false,
+                    checksum => checksum?,
+                }
+                match self.expected_len {
+                    Some(expected) if expected != actual => {
+                        Err(Error::WrongLength { expected, actual })
+                    }
+                    _ => Ok(actual),
+                }
+            };
+
+            let max_decoded_len = max_decoded_len(input, self.alpha);
+            let result = match self.check {
+                Check::Disabled => output.decode_with(max_decoded_len, |output| {
+                    verify(decode_into(input, output, self.alpha).map(|len| (len, Ok(()))))
+                }),
+                #[cfg(feature = "check-core")]
+                Check::Enabled(expected_ver) => output.decode_with(max_decoded_len, |output| {
+                    verify(decode_check_into(
+                        input,
+                        output,
+                        self.alpha,
+                        expected_ver.as_bytes(),
+                        self.trailing_version,
+                        self.check_range,
+                        (self.sha256, self.sha256_incremental),
+                    ))
+                }),
+                #[cfg(feature = "cb58-core")]
+                Check::CB58(expected_ver) => output.decode_with(max_decoded_len, |output| {
+                    verify(decode_cb58_into(
+                        input,
+                        output,
+                        self.alpha,
+                        expected_ver.as_bytes(),
+                        self.sha256,
+                    ))
+                }),
+                #[cfg(feature = "check-core")]
+                Check::Delimited(delimiter) => output.decode_with(max_decoded_len, |output| {
+                    verify(decode_check_delimited_into(
+                        input,
+                        output,
+                        self.alpha,
+                        delimiter,
+                        self.sha256,
+                    ))
+                }),
+                Check::Custom { len, f } => output.decode_with(max_decoded_len, |output| {
+                    verify(decode_check_fn_into(input, output, self.alpha, len, f))
+                }),
+            };
+
+            // The ignored characters, prefix and padding are always valid, so errors only need their
+            // index moved past them
+            match result {
+                Ok(len) => Ok((len, checksum_valid)),
+                Err(Error::InvalidCharacter { character, index }) => Err(Error::InvalidCharacter {
+                    character,
+                    index: index + offset,
+                }),
+                Err(Error::NonAsciiCharacter { index }) => Err(Error::NonAsciiCharacter {
+                    index: index + offset,
+                }),
+                Err(err) => Err(err),
+            }
+        })
     }
 }
 
 /// For `const` compatibility we are restricted to using a concrete input and output type, as
 /// `const` trait implementations and `&mut` are unstable. These methods will eventually be
 /// deprecated once the primary interfaces can be converted into `const fn` directly.
-impl<'a, 'b> DecodeBuilder<'a, &'b [u8]> {
+impl<'a, 'b, H> DecodeBuilder<'a, &'b [u8], H> {
     /// Decode into a new array.
     ///
     /// Returns the decoded array as bytes.
@@ -374,6 +2274,23 @@ impl<'a, 'b> DecodeBuilder<'a, &'b [u8]> {
             matches!(self.check, Check::Disabled),
             "checksums in const aren't supported (why are you using this API at runtime)",
         );
+        #[cfg(feature = "percent-encoding")]
+        assert!(
+            !self.percent_decode,
+            "percent-decoding in const isn't supported (why are you using this API at runtime)",
+        );
+        assert!(
+            !self.padded,
+            "padding in const isn't supported (why are you using this API at runtime)",
+        );
+        assert!(
+            self.prefix.is_empty(),
+            "prefixes in const aren't supported (why are you using this API at runtime)",
+        );
+        assert!(
+            !self.sanitize_unicode,
+            "sanitizing unicode in const isn't supported (why are you using this API at runtime)",
+        );
         decode_into_const(self.input, self.alpha)
     }
 
@@ -406,11 +2323,343 @@ impl<'a, 'b> DecodeBuilder<'a, &'b [u8]> {
             Err(err) => err.unwrap_const(),
         }
     }
+
+    /// [`Self::into_array_const`] but any error is discarded, returning `None` for invalid input
+    /// so that `const` code can handle it without matching on [`Error`], as a simple
+    /// `into_array_const().ok()` isn't possible yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// const WORLD: Option<[u8; 5]> = bs58::decode(b"EUYUqQf".as_slice()).into_array_const_opt();
+    /// const INVALID: Option<[u8; 5]> = bs58::decode(b"EUYUqQ0".as_slice()).into_array_const_opt();
+    /// const TOO_LONG: Option<[u8; 4]> = bs58::decode(b"EUYUqQf".as_slice()).into_array_const_opt();
+    ///
+    /// assert_eq!(Some(*b"world"), WORLD);
+    /// assert_eq!(None, INVALID);
+    /// assert_eq!(None, TOO_LONG);
+    /// ```
+    pub const fn into_array_const_opt<const N: usize>(self) -> Option<[u8; N]> {
+        match self.into_array_const() {
+            Ok(result) => Some(result),
+            Err(_) => None,
+        }
+    }
+}
+
+/// Removes the characters ignored by [`DecodeBuilder::sanitize_unicode`] from both ends of the
+/// input, returning the rest along with how many bytes were removed from the start.
+fn sanitize_unicode(input: &[u8]) -> (&[u8], usize) {
+    const IGNORED: [&[u8]; 5] = [
+        "\u{feff}".as_bytes(),
+        "\u{200b}".as_bytes(),
+        "\u{200c}".as_bytes(),
+        "\u{200d}".as_bytes(),
+        "\u{2060}".as_bytes(),
+    ];
+
+    let mut rest = input;
+    while let Some(stripped) = IGNORED.iter().find_map(|c| rest.strip_prefix(*c)) {
+        rest = stripped;
+    }
+    let start = input.len() - rest.len();
+    while let Some(stripped) = IGNORED.iter().find_map(|c| rest.strip_suffix(*c)) {
+        rest = stripped;
+    }
+    (rest, start)
+}
+
+#[cfg(feature = "percent-encoding")]
+fn percent_decode(input: &[u8]) -> Result<alloc::borrow::Cow<'_, [u8]>> {
+    for (i, _) in input.iter().enumerate().filter(|(_, c)| **c == b'%') {
+        match input.get(i + 1..i + 3) {
+            Some([a, b]) if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {}
+            _ => return Err(Error::InvalidPercentEncoding { index: i }),
+        }
+    }
+    Ok(percent_encoding::percent_decode(input).into())
+}
+
+pub(crate) fn cmp(a: &[u8], b: &[u8], alpha: &Alphabet) -> Result<core::cmp::Ordering> {
+    let (a_zeros, a) = split_zeros(a, alpha)?;
+    let (b_zeros, b) = split_zeros(b, alpha)?;
+    let a_digits = a.iter().map(|c| alpha.decode[*c as usize]);
+    let b_digits = b.iter().map(|c| alpha.decode[*c as usize]);
+
+    // After stripping leading zeros the first digit is non-zero, so more digits is a larger value
+    Ok(a.len()
+        .cmp(&b.len())
+        .then_with(|| a_digits.cmp(b_digits))
+        .then(a_zeros.cmp(&b_zeros)))
+}
+
+#[cfg(all(any(feature = "check-core", feature = "cb58-core"), feature = "alloc"))]
+pub(crate) fn inspect_check(
+    input: &[u8],
+    scheme: CheckScheme,
+    sha256: Sha256Fn,
+) -> Result<CheckParts> {
+    let mut decoded = DecodeBuilder::from_input(input).into_vec()?;
+    let checksum_index = decoded
+        .len()
+        .checked_sub(CHECKSUM_LEN)
+        .ok_or(Error::NoChecksum)?;
+
+    let mut checksum = [0; CHECKSUM_LEN];
+    checksum.copy_from_slice(&decoded[checksum_index..]);
+    decoded.truncate(checksum_index);
+
+    let calculated = match scheme {
+        #[cfg(feature = "check-core")]
+        CheckScheme::Check => crate::encode::check_checksum(&decoded, &[], sha256),
+        #[cfg(feature = "cb58-core")]
+        CheckScheme::CB58 => crate::encode::cb58_checksum(&decoded, &[], sha256),
+    };
+
+    let version = (!decoded.is_empty()).then(|| decoded.remove(0));
+    Ok(CheckParts {
+        version,
+        payload: decoded,
+        checksum,
+        checksum_valid: calculated == checksum,
+    })
+}
+
+/// Validate the input characters, then split off the leading zeros returning their count.
+fn split_zeros<'a>(input: &'a [u8], alpha: &Alphabet) -> Result<(usize, &'a [u8])> {
+    for (i, c) in input.iter().enumerate() {
+        if *c > 127 {
+            return Err(Error::NonAsciiCharacter { index: i });
+        }
+        if alpha.decode[*c as usize] == 0xFF {
+            return Err(Error::InvalidCharacter {
+                character: *c as char,
+                index: i,
+            });
+        }
+    }
+    let zeros = input.iter().take_while(|c| alpha.is_zero(**c)).count();
+    Ok((zeros, &input[zeros..]))
+}
+
+/// An upper bound on the decoded length of `input`, used to size resizeable targets: each leading
+/// zero character decodes to one byte, and every other character contributes log256(58) ≈ 0.7322
+/// bytes, rounded up to 733 / 1000.
+pub(crate) fn max_decoded_len(input: &[u8], alpha: &Alphabet) -> usize {
+    let zeros = input.iter().take_while(|c| alpha.is_zero(**c)).count();
+    let digits = input.len() - zeros;
+    zeros + digits.saturating_mul(733).div_ceil(1000)
+}
+
+/// Decode `input` into `output` using the alphabet `A`, returning the decoded length.
+///
+/// Unlike [`DecodeBuilder`], which takes the alphabet as a runtime reference, this generates a
+/// copy of the decoder specialized to `A`, allowing the compiler to resolve the alphabet lookups
+/// at compile time. Prefer the builder unless benchmarks show a hot path benefits from this.
+///
+/// # Examples
+///
+/// ```rust
+/// use bs58::alphabet::{Bitcoin, Ripple};
+///
+/// let mut output = [0; 8];
+///
+/// let len = bs58::decode::decode_static_into::<Bitcoin>(b"he11owor1d", &mut output)?;
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], output[..len]);
+///
+/// let len = bs58::decode::decode_static_into::<Ripple>(b"6erroAoird", &mut output)?;
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], output[..len]);
+///
+/// assert_eq!(
+///     bs58::decode::Error::InvalidCharacter { character: 'l', index: 2 },
+///     bs58::decode::decode_static_into::<Bitcoin>(b"hello world", &mut output).unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+pub fn decode_static_into<A: StaticAlphabet>(input: &[u8], output: &mut [u8]) -> Result<usize> {
+    decode_into_inline(input, output, A::ALPHABET, |_, _| {})
 }
 
 fn decode_into(input: &[u8], output: &mut [u8], alpha: &Alphabet) -> Result<usize> {
+    decode_into_inline(input, output, alpha, |_, _| {})
+}
+
+/// The number of decoded bytes passed to `emit` at a time by [`decode_into_emitting`], the
+/// SHA-256 block size.
+const EMIT_BLOCK_LEN: usize = 64;
+
+/// Decode like [`decode_into`], also passing the total decoded length and each successive block of
+/// the output to `emit` as soon as it is written, to hash it while it is produced.
+#[cfg(feature = "check-core")]
+fn decode_into_emitting(
+    input: &[u8],
+    output: &mut [u8],
+    alpha: &Alphabet,
+    emit: impl FnMut(usize, &[u8]),
+) -> Result<usize> {
+    decode_into_inline(input, output, alpha, emit)
+}
+
+// Always inlined so that `decode_static_into` gets a copy with its alphabet known, and the no-op
+// `emit` of the unchecked decoders is optimized out
+#[inline(always)]
+fn decode_into_inline(
+    input: &[u8],
+    output: &mut [u8],
+    alpha: &Alphabet,
+    emit: impl FnMut(usize, &[u8]),
+) -> Result<usize> {
+    // Reject input that can't possibly fit before doing any arithmetic: after the leading zeros
+    // every character but the first contributes over 5.8 bits, 29 / 40 bytes is a lower bound.
+    // The characters are validated first so that invalid input is always reported as such,
+    // however long it is.
+    let (zeros, _) = split_zeros(input, alpha)?;
+    if let Some(digits) = (input.len() - zeros).checked_sub(1) {
+        if zeros + digits.saturating_mul(29) / 40 + 1 > output.len() {
+            return Err(Error::BufferTooSmall);
+        }
+    }
+
+    // Chosen from the `limbs` benches, see `LIMBS_MIN_DIGITS`
+    if (LIMBS_MIN_DIGITS..=LIMBS_MAX_DIGITS).contains(&(input.len() - zeros)) {
+        decode_into_limbs(input, output, alpha, zeros, emit)
+    } else {
+        decode_into_bytes(input, output, alpha, zeros, emit)
+    }
+}
+
+/// Decode `input` using only the limb decoder, for benchmarking it against
+/// [`decode_bytes_into`]. Returns [`Error::BufferTooSmall`] for values too large for the limbs.
+#[doc(hidden)]
+pub fn decode_limbs_into(input: &[u8], output: &mut [u8], alpha: &Alphabet) -> Result<usize> {
+    let (zeros, rest) = split_zeros(input, alpha)?;
+    if rest.len() > LIMBS_MAX_DIGITS {
+        return Err(Error::BufferTooSmall);
+    }
+    decode_into_limbs(input, output, alpha, zeros, |_, _| {})
+}
+
+/// Decode `input` using only the byte at a time decoder, for benchmarking it against
+/// [`decode_limbs_into`].
+#[doc(hidden)]
+pub fn decode_bytes_into(input: &[u8], output: &mut [u8], alpha: &Alphabet) -> Result<usize> {
+    let (zeros, _) = split_zeros(input, alpha)?;
+    decode_into_bytes(input, output, alpha, zeros, |_, _| {})
+}
+
+/// Digits processed per step by [`decode_into_limbs`], `58^5` is the largest power that fits in
+/// a `u32` limb.
+const LIMB_DIGITS: usize = 5;
+const LIMB_MULTIPLIERS: [u64; LIMB_DIGITS + 1] = [
+    1,
+    58,
+    58 * 58,
+    58 * 58 * 58,
+    58 * 58 * 58 * 58,
+    58 * 58 * 58 * 58 * 58,
+];
+/// The number of `u32` limbs kept on the stack by [`decode_into_limbs`], enough for 256 byte
+/// keys and hashes, longer input uses [`decode_into_bytes`].
+const LIMBS: usize = 80;
+/// Each digit adds less than 5.86 bits, so this many always fit in [`LIMBS`].
+const LIMBS_MAX_DIGITS: usize = LIMBS * 32 * 100 / 586;
+/// The fewest digits decoded by [`decode_into_limbs`]. In the `limbs` benches the byte at a time
+/// decoder was faster for values of 1 and 2 bytes, up to 3 digits, and the limbs were faster from
+/// 3 bytes: about 1.1x for 5 bytes, 3.4x for 32 bytes, 6.6x for 64 bytes and 14x for 256 bytes.
+const LIMBS_MIN_DIGITS: usize = 4;
+
+/// Decodes [`LIMB_DIGITS`] characters at a time into little-endian `u32` limbs, rather than a
+/// byte at a time like [`decode_into_bytes`], so each step does a fifth of the work over a
+/// quarter as many limbs.
+///
+/// Returns the same results and errors as [`decode_into_bytes`]: as the value only grows with
+/// each character, the buffer would overflow before an invalid character exactly when the value
+/// of the characters before it doesn't fit.
+#[inline(always)]
+fn decode_into_limbs(
+    input: &[u8],
+    output: &mut [u8],
+    alpha: &Alphabet,
+    zeros: usize,
+    mut emit: impl FnMut(usize, &[u8]),
+) -> Result<usize> {
+    let mut limbs = [0u32; LIMBS];
+    let mut len = 0;
+    let mut invalid = None;
+
+    for (chunk_index, chunk) in input[zeros..].chunks(LIMB_DIGITS).enumerate() {
+        let mut digits = 0u64;
+        let mut count = 0;
+        for (i, c) in chunk.iter().enumerate() {
+            let index = zeros + chunk_index * LIMB_DIGITS + i;
+            if *c > 127 {
+                invalid = Some(Error::NonAsciiCharacter { index });
+                break;
+            }
+            let val = alpha.decode[*c as usize];
+            if val == 0xFF {
+                invalid = Some(Error::InvalidCharacter {
+                    character: *c as char,
+                    index,
+                });
+                break;
+            }
+            digits = digits * 58 + u64::from(val);
+            count += 1;
+        }
+
+        let mut carry = digits;
+        for limb in &mut limbs[..len] {
+            carry += u64::from(*limb) * LIMB_MULTIPLIERS[count];
+            *limb = carry as u32;
+            carry >>= 32;
+        }
+        if carry > 0 {
+            limbs[len] = carry as u32;
+            len += 1;
+        }
+
+        if invalid.is_some() {
+            break;
+        }
+    }
+
+    let significant = match limbs[..len].last() {
+        Some(last) => (len - 1) * 4 + 4 - last.leading_zeros() as usize / 8,
+        None => 0,
+    };
+    if significant > output.len() {
+        return Err(Error::BufferTooSmall);
+    }
+    if let Some(err) = invalid {
+        return Err(err);
+    }
+    let total = zeros + significant;
+    let output = output.get_mut(..total).ok_or(Error::BufferTooSmall)?;
+
+    // Written front to back in blocks so each can be emitted once complete, byte `i` from the end
+    // is the `i % 4`th byte of limb `i / 4`
+    output[..zeros].fill(0);
+    for start in (0..total).step_by(EMIT_BLOCK_LEN) {
+        let end = (start + EMIT_BLOCK_LEN).min(total);
+        let first = start.max(zeros).min(end);
+        for (j, byte) in (first..end).zip(&mut output[first..end]) {
+            let i = total - 1 - j;
+            *byte = (limbs[i / 4] >> (i % 4 * 8)) as u8;
+        }
+        emit(total, &output[start..end]);
+    }
+    Ok(total)
+}
+
+#[inline(always)]
+fn decode_into_bytes(
+    input: &[u8],
+    output: &mut [u8],
+    alpha: &Alphabet,
+    zeros: usize,
+    mut emit: impl FnMut(usize, &[u8]),
+) -> Result<usize> {
     let mut index = 0;
-    let zero = alpha.encode[0];
 
     for (i, c) in input.iter().enumerate() {
         if *c > 127 {
@@ -439,71 +2688,205 @@ fn decode_into(input: &[u8], output: &mut [u8], alpha: &Alphabet) -> Result<usiz
         }
     }
 
-    for _ in input.iter().take_while(|c| **c == zero) {
+    for _ in 0..zeros {
         let byte = output.get_mut(index).ok_or(Error::BufferTooSmall)?;
         *byte = 0;
         index += 1;
     }
 
-    output[..index].reverse();
+    // Reversed a block at a time from the front so each can be emitted once complete, the blocks
+    // past the middle are already in place from swapping the ones before it
+    let output = &mut output[..index];
+    for start in (0..index).step_by(EMIT_BLOCK_LEN) {
+        let end = (start + EMIT_BLOCK_LEN).min(index);
+        for i in start..end.min(index / 2) {
+            output.swap(i, index - 1 - i);
+        }
+        emit(index, &output[start..end]);
+    }
     Ok(index)
 }
 
-#[cfg(feature = "check")]
-fn decode_check_into(
+// The checked decoders return the length of the payload along with the result of verifying its
+// checksum, so that `into_vec_lenient` can keep the payload when only the checksum is wrong
+
+#[cfg(feature = "check-core")]
+fn decode_check_delimited_into(
     input: &[u8],
     output: &mut [u8],
     alpha: &Alphabet,
-    expected_ver: Option<u8>,
-) -> Result<usize> {
-    use sha2::{Digest, Sha256};
+    delimiter: u8,
+    sha256: Sha256Fn,
+) -> Result<(usize, Result<()>)> {
+    let Some(split) = input.iter().position(|c| *c == delimiter) else {
+        return Err(Error::NoChecksum);
+    };
+    let (payload, checksum) = (&input[..split], &input[split + 1..]);
+
+    let len = decode_into(payload, output, alpha)?;
+
+    let mut expected_checksum = [0; CHECKSUM_LEN];
+    let offset = split + 1;
+    let checksum_len =
+        decode_into(checksum, &mut expected_checksum, alpha).map_err(|err| match err {
+            Error::BufferTooSmall => Error::NoChecksum,
+            Error::InvalidCharacter { character, index } => Error::InvalidCharacter {
+                character,
+                index: index + offset,
+            },
+            Error::NonAsciiCharacter { index } => Error::NonAsciiCharacter {
+                index: index + offset,
+            },
+            err => err,
+        })?;
+    if checksum_len != CHECKSUM_LEN {
+        return Err(Error::NoChecksum);
+    }
 
+    let checksum = crate::encode::check_checksum(&output[..len], &[], sha256);
+    if checksum != expected_checksum {
+        let mismatch = Error::InvalidChecksum {
+            checksum: expected_checksum,
+            expected_checksum: checksum,
+        };
+        return Ok((len, Err(mismatch)));
+    }
+    Ok((len, Ok(())))
+}
+
+fn decode_check_fn_into(
+    input: &[u8],
+    output: &mut [u8],
+    alpha: &Alphabet,
+    len: usize,
+    f: CheckFn<'_>,
+) -> Result<(usize, Result<()>)> {
     let decoded_len = decode_into(input, output, alpha)?;
+    let payload_len = decoded_len
+        .checked_sub(len)
+        .ok_or(Error::InvalidCheckFnChecksum)?;
+    let (payload, checksum) = output[..decoded_len].split_at(payload_len);
+
+    let mut expected = [0; MAX_CHECK_FN_LEN];
+    f(payload, &mut expected[..len]);
+    if checksum != &expected[..len] {
+        return Ok((payload_len, Err(Error::InvalidCheckFnChecksum)));
+    }
+    Ok((payload_len, Ok(())))
+}
+
+#[cfg(feature = "check-core")]
+fn decode_check_into(
+    input: &[u8],
+    output: &mut [u8],
+    alpha: &Alphabet,
+    expected_ver: &[u8],
+    trailing_version: bool,
+    range: (Bound<usize>, Bound<usize>),
+    (sha256, sha256_incremental): (Sha256Fn, Sha256IncrementalFn),
+) -> Result<(usize, Result<()>)> {
+    let start = match range.0 {
+        Bound::Included(start) => start,
+        Bound::Excluded(start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    // The default end is the checksum index, which is only known once decoding has started
+    let end = |decoded_len: usize| match range.1 {
+        Bound::Included(end) => end.saturating_add(1),
+        Bound::Excluded(end) => end,
+        Bound::Unbounded => decoded_len.saturating_sub(CHECKSUM_LEN),
+    };
+
+    // Hash the covered part of each block of the payload as it is decoded, saving a second pass
+    // over the output, or decode first and hash afterwards if the hasher can't do so
+    let mut decoded = None;
+    let first_hash = sha256_incremental(&mut |update| {
+        let mut offset = 0;
+        decoded = Some(decode_into_emitting(
+            input,
+            output,
+            alpha,
+            |decoded_len, block| {
+                let (block_start, block_end) = (offset, offset + block.len());
+                offset = block_end;
+                let from = start.clamp(block_start, block_end) - block_start;
+                let to = end(decoded_len).clamp(block_start, block_end) - block_start;
+                if from < to {
+                    update(&block[from..to]);
+                }
+            },
+        ));
+    });
+    let (decoded_len, first_hash) = match (decoded, first_hash) {
+        (Some(decoded_len), first_hash) => (decoded_len?, first_hash),
+        (None, _) => (decode_into(input, output, alpha)?, None),
+    };
+
     if decoded_len < CHECKSUM_LEN {
         return Err(Error::NoChecksum);
     }
     let checksum_index = decoded_len - CHECKSUM_LEN;
+    let end = end(decoded_len);
+    if start > end || end > checksum_index {
+        return Err(Error::InvalidChecksumRange {
+            start,
+            end,
+            len: checksum_index,
+        });
+    }
 
     let expected_checksum = &output[checksum_index..decoded_len];
 
-    let first_hash = Sha256::digest(&output[0..checksum_index]);
-    let second_hash = Sha256::digest(first_hash);
+    let first_hash = first_hash.unwrap_or_else(|| sha256(&[&output[start..end]]));
+    let second_hash = sha256(&[&first_hash]);
     let (checksum, _) = second_hash.split_at(CHECKSUM_LEN);
 
     if checksum == expected_checksum {
-        if let Some(ver) = expected_ver {
-            if output[0] == ver {
-                Ok(checksum_index)
-            } else {
-                Err(Error::InvalidVersion {
-                    ver: output[0],
-                    expected_ver: ver,
-                })
-            }
+        if trailing_version {
+            let version_index = checksum_index
+                .checked_sub(expected_ver.len())
+                .ok_or(Error::NoChecksum)?;
+            check_version(&output[version_index..checksum_index], expected_ver)?;
         } else {
-            Ok(checksum_index)
+            check_version(&output[..decoded_len], expected_ver)?;
         }
+        Ok((checksum_index, Ok(())))
     } else {
         let mut a: [u8; CHECKSUM_LEN] = Default::default();
         a.copy_from_slice(checksum);
         let mut b: [u8; CHECKSUM_LEN] = Default::default();
         b.copy_from_slice(expected_checksum);
-        Err(Error::InvalidChecksum {
+        let mismatch = Error::InvalidChecksum {
             checksum: a,
             expected_checksum: b,
-        })
+        };
+        Ok((checksum_index, Err(mismatch)))
+    }
+}
+
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+fn check_version(decoded: &[u8], expected_ver: &[u8]) -> Result<()> {
+    if decoded.len() < expected_ver.len() {
+        return Err(Error::NoChecksum);
+    }
+    match decoded
+        .iter()
+        .zip(expected_ver)
+        .find(|(ver, expected)| ver != expected)
+    {
+        Some((&ver, &expected_ver)) => Err(Error::InvalidVersion { ver, expected_ver }),
+        None => Ok(()),
     }
 }
 
-#[cfg(feature = "cb58")]
+#[cfg(feature = "cb58-core")]
 fn decode_cb58_into(
     input: &[u8],
     output: &mut [u8],
     alpha: &Alphabet,
-    expected_ver: Option<u8>,
-) -> Result<usize> {
-    use sha2::{Digest, Sha256};
-
+    expected_ver: &[u8],
+    sha256: Sha256Fn,
+) -> Result<(usize, Result<()>)> {
     let decoded_len = decode_into(input, output, alpha)?;
     if decoded_len < CHECKSUM_LEN {
         return Err(Error::NoChecksum);
@@ -512,38 +2895,28 @@ fn decode_cb58_into(
 
     let expected_checksum = &output[checksum_index..decoded_len];
 
-    let hash = Sha256::digest(&output[0..checksum_index]);
+    let hash = sha256(&[&output[0..checksum_index]]);
     let (_, checksum) = hash.split_at(hash.len() - CHECKSUM_LEN);
 
     if checksum == expected_checksum {
-        if let Some(ver) = expected_ver {
-            if output[0] == ver {
-                Ok(checksum_index)
-            } else {
-                Err(Error::InvalidVersion {
-                    ver: output[0],
-                    expected_ver: ver,
-                })
-            }
-        } else {
-            Ok(checksum_index)
-        }
+        check_version(&output[..decoded_len], expected_ver)?;
+        Ok((checksum_index, Ok(())))
     } else {
         let mut a: [u8; CHECKSUM_LEN] = Default::default();
         a.copy_from_slice(checksum);
         let mut b: [u8; CHECKSUM_LEN] = Default::default();
         b.copy_from_slice(expected_checksum);
-        Err(Error::InvalidChecksum {
+        let mismatch = Error::InvalidChecksum {
             checksum: a,
             expected_checksum: b,
-        })
+        };
+        Ok((checksum_index, Err(mismatch)))
     }
 }
 
 const fn decode_into_const<const N: usize>(input: &[u8], alpha: &Alphabet) -> Result<[u8; N]> {
     let mut output = [0u8; N];
     let mut index = 0;
-    let zero = alpha.encode[0];
 
     let mut i = 0;
     while i < input.len() {
@@ -581,7 +2954,7 @@ const fn decode_into_const<const N: usize>(input: &[u8], alpha: &Alphabet) -> Re
     }
 
     let mut i = 0;
-    while i < input.len() && input[i] == zero {
+    while i < input.len() && alpha.is_zero(input[i]) {
         if index >= output.len() {
             return Err(Error::BufferTooSmall);
         }
@@ -623,7 +2996,7 @@ impl fmt::Display for Error {
                 "provided string contained non-ascii character starting at byte {}",
                 index
             ),
-            #[cfg(any(feature = "check", feature = "cb58"))]
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
             Error::InvalidChecksum {
                 checksum,
                 expected_checksum,
@@ -632,19 +3005,209 @@ impl fmt::Display for Error {
                 "invalid checksum, calculated checksum: '{:?}', expected checksum: {:?}",
                 checksum, expected_checksum
             ),
-            #[cfg(any(feature = "check", feature = "cb58"))]
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
             Error::InvalidVersion { ver, expected_ver } => write!(
                 f,
                 "invalid version, payload version: '{:?}', expected version: {:?}",
                 ver, expected_ver
             ),
-            #[cfg(any(feature = "check", feature = "cb58"))]
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
             Error::NoChecksum => write!(f, "provided string is too small to contain a checksum"),
+            #[cfg(feature = "check-core")]
+            Error::NoNetworkVersion => write!(
+                f,
+                "provided string is too small to contain a network and version byte"
+            ),
+            Error::InputTooShort { expected, actual } => write!(
+                f,
+                "provided input was {} bytes long, expected at least {}",
+                actual, expected
+            ),
+            #[cfg(feature = "percent-encoding")]
+            Error::InvalidPercentEncoding { index } => write!(
+                f,
+                "provided string contained invalid percent-encoding at byte {}",
+                index
+            ),
+            #[cfg(feature = "check-core")]
+            Error::InvalidChecksumRange { start, end, len } => write!(
+                f,
+                "checksum range {}..{} is not within the {} byte payload",
+                start, end, len
+            ),
+            Error::MissingPrefix => {
+                write!(f, "provided string did not start with the expected prefix")
+            }
+            Error::UnalignedLength { len, alignment } => write!(
+                f,
+                "decoded data was {} bytes long, not a multiple of {}",
+                len, alignment
+            ),
+            Error::WrongLength { expected, actual } => write!(
+                f,
+                "decoded data was {} bytes long, expected {}",
+                actual, expected
+            ),
+            Error::InvalidCrc32 { crc, expected_crc } => write!(
+                f,
+                "crc-32 of the provided string was {:08x}, footer contained {:08x}",
+                crc, expected_crc
+            ),
+            Error::InvalidCheckFnChecksum => {
+                write!(f, "decoded data did not end with the expected checksum")
+            }
+            Error::WrongLeadingZeros { expected, actual } => write!(
+                f,
+                "decoded data started with {} zero bytes, expected {}",
+                actual, expected
+            ),
+            Error::IntegerOverflow => write!(f, "decoded data was too long to fit in an integer"),
+            Error::IntegerZero => write!(f, "decoded integer was zero"),
+            Error::InvalidUuidLength { len } => write!(
+                f,
+                "encoded uuid was {} characters long, expected {}",
+                len,
+                crate::UUID_ENCODED_LEN
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerboseError<'_> {}
+
+impl fmt::Display for VerboseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)?;
+        if let Some(suggestion) = self.suggestion() {
+            write!(f, ", did you mean '{}'?", suggestion)?;
+        } else if let Error::InvalidCharacter { .. } = self.error {
+            // The alphabet is guaranteed ASCII
+            let valid = core::str::from_utf8(&self.alphabet.encode).map_err(|_| fmt::Error)?;
+            write!(f, ", valid characters are: {}", valid)?;
+        }
+        Ok(())
+    }
+}
+
+impl VerboseError<'_> {
+    /// If the error is an [`Error::InvalidCharacter`] whose character is valid in the alphabet
+    /// when its case is swapped, returns that case variant.
+    ///
+    /// Decoding is always case-sensitive, so this helps diagnose input that was written in the
+    /// wrong case or decoded with an alphabet differing only in case (e.g.
+    /// [`Alphabet::BITCOIN`] vs [`Alphabet::FLICKR`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let err = bs58::decode("he11oworld").into_vec().unwrap_err();
+    /// let err = err.with_alphabet(bs58::Alphabet::DEFAULT);
+    /// assert_eq!(Some('L'), err.suggestion());
+    /// assert_eq!(
+    ///     "provided string contained invalid character 'l' at byte 8, did you mean 'L'?",
+    ///     err.to_string());
+    /// ```
+    pub fn suggestion(&self) -> Option<char> {
+        let Error::InvalidCharacter { character, .. } = self.error else {
+            return None;
+        };
+        let swapped = if character.is_ascii_lowercase() {
+            character.to_ascii_uppercase()
+        } else if character.is_ascii_uppercase() {
+            character.to_ascii_lowercase()
+        } else {
+            return None;
+        };
+        if self.alphabet.decode[swapped as usize] == 0xFF {
+            return None;
         }
+        Some(swapped)
     }
 }
 
 impl Error {
+    /// Attach the alphabet that was being decoded with to this error, to give a more helpful
+    /// error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let err = bs58::decode("he11o world")
+    ///     .with_alphabet(bs58::Alphabet::FLICKR)
+    ///     .into_vec()
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     "provided string contained invalid character ' ' at byte 5, valid characters are: \
+    ///      123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+    ///     err.with_alphabet(bs58::Alphabet::FLICKR).to_string());
+    /// ```
+    pub const fn with_alphabet(self, alphabet: &Alphabet) -> VerboseError<'_> {
+        VerboseError {
+            error: self,
+            alphabet,
+        }
+    }
+
+    /// Render up to `window` characters either side of the character an
+    /// [`Error::InvalidCharacter`] or [`Error::NonAsciiCharacter`] refers to, with it highlighted
+    /// in brackets, for showing where in a long input the problem is.
+    ///
+    /// `input` must be the string that was decoded, as the error only stores an index into it.
+    /// Characters cut off either end are replaced by `...`. Returns `None` for other errors, or if
+    /// the index doesn't refer to a character in `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = "123456789abc!defghijk";
+    /// let err = bs58::decode(input).into_vec().unwrap_err();
+    /// assert_eq!(Some("...89abc[!]defgh...".to_owned()), err.context(input, 5));
+    /// assert_eq!(Some("123456789abc[!]defghijk".to_owned()), err.context(input, 20));
+    ///
+    /// let input = "he11o w\u{f6}r1d";
+    /// let err = bs58::decode(input).into_vec().unwrap_err();
+    /// assert_eq!(Some("...1o[ ]w\u{f6}...".to_owned()), err.context(input, 2));
+    ///
+    /// assert_eq!(None, bs58::decode::Error::BufferTooSmall.context(input, 2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn context(&self, input: &str, window: usize) -> Option<String> {
+        let index = match *self {
+            Error::InvalidCharacter { index, .. } | Error::NonAsciiCharacter { index } => index,
+            _ => return None,
+        };
+        let (before, rest) = (input.get(..index)?, input.get(index..)?);
+        let mut after = rest.chars();
+        let character = after.next()?;
+        let after = after.as_str();
+
+        let start = before
+            .char_indices()
+            .rev()
+            .take(window)
+            .last()
+            .map_or(before.len(), |(i, _)| i);
+        let end = after
+            .char_indices()
+            .nth(window)
+            .map_or(after.len(), |(i, _)| i);
+
+        let mut context = String::new();
+        if start > 0 {
+            context.push_str("...");
+        }
+        context.push_str(&before[start..]);
+        context.push('[');
+        context.push(character);
+        context.push(']');
+        context.push_str(&after[..end]);
+        if end < after.len() {
+            context.push_str("...");
+        }
+        Some(context)
+    }
+
     /// Panic with an error message based on this error. This cannot include any of the dynamic
     /// content because formatting in `const` is not yet possible.
     pub const fn unwrap_const(self) -> ! {
@@ -656,12 +3219,93 @@ impl Error {
             Error::NonAsciiCharacter { .. } => {
                 panic!("provided string contained non-ascii character")
             }
-            #[cfg(any(feature = "check", feature = "cb58"))]
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
             Error::InvalidChecksum { .. } => panic!("invalid checksum"),
-            #[cfg(any(feature = "check", feature = "cb58"))]
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
             Error::InvalidVersion { .. } => panic!("invalid version"),
-            #[cfg(any(feature = "check", feature = "cb58"))]
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
             Error::NoChecksum => panic!("provided string is too small to contain a checksum"),
+            #[cfg(feature = "check-core")]
+            Error::NoNetworkVersion => panic!("no network and version found"),
+            Error::InputTooShort { .. } => panic!("provided input was too short"),
+            #[cfg(feature = "percent-encoding")]
+            Error::InvalidPercentEncoding { .. } => {
+                panic!("provided string contained invalid percent-encoding")
+            }
+            #[cfg(feature = "check-core")]
+            Error::InvalidChecksumRange { .. } => {
+                panic!("checksum range was not within the payload")
+            }
+            Error::MissingPrefix => {
+                panic!("provided string did not start with the expected prefix")
+            }
+            Error::UnalignedLength { .. } => {
+                panic!("decoded data was not a multiple of the packed value size")
+            }
+            Error::WrongLength { .. } => panic!("decoded data was not the expected length"),
+            Error::InvalidCrc32 { .. } => panic!("invalid crc-32"),
+            Error::InvalidCheckFnChecksum => panic!("invalid checksum"),
+            Error::WrongLeadingZeros { .. } => {
+                panic!("decoded data did not start with the expected number of zero bytes")
+            }
+            Error::IntegerOverflow => panic!("decoded data was too long to fit in an integer"),
+            Error::IntegerZero => panic!("decoded integer was zero"),
+            Error::InvalidUuidLength { .. } => panic!("encoded uuid was the wrong length"),
+        }
+    }
+
+    /// Whether this error was caused by the input, such as a typo or corrupted data, so could be
+    /// shown to an end user to correct, rather than by how the decoder was used, such as a buffer
+    /// that is too small, which indicates a bug in the application.
+    ///
+    /// [`Error::BufferTooSmall`] is ambiguous and classified as misuse, as this can't tell how the
+    /// buffer was sized. When decoding into a buffer sized for the expected data, such as with
+    /// [`DecodeBuilder::into_array_const`], an array passed to [`DecodeBuilder::onto`] or
+    /// `solana::decode_pubkey`, it means the input was too long,
+    /// which callers should treat as a user input error themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert!(bs58::decode("he11o world").into_vec().unwrap_err().is_user_input_error());
+    /// assert!(!bs58::decode("he11owor1d").onto(&mut [0; 1]).unwrap_err().is_user_input_error());
+    ///
+    /// // A 32 byte key pasted with extra characters, the fixed size buffer makes this user input
+    /// let pasted = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi4vJ9JU1";
+    /// match bs58::decode(pasted).onto(&mut [0; 32]) {
+    ///     Err(bs58::decode::Error::BufferTooSmall) => println!("that key is too long"),
+    ///     Err(err) if err.is_user_input_error() => println!("invalid key: {err}"),
+    ///     Err(err) => panic!("{err}"),
+    ///     Ok(_) => panic!("decoded an overlong key"),
+    /// }
+    /// ```
+    pub const fn is_user_input_error(&self) -> bool {
+        match self {
+            Error::BufferTooSmall => false,
+            Error::InvalidCharacter { .. } => true,
+            Error::NonAsciiCharacter { .. } => true,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            Error::InvalidChecksum { .. } => true,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            Error::InvalidVersion { .. } => true,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            Error::NoChecksum => true,
+            #[cfg(feature = "check-core")]
+            Error::NoNetworkVersion => true,
+            Error::InputTooShort { .. } => true,
+            #[cfg(feature = "percent-encoding")]
+            Error::InvalidPercentEncoding { .. } => true,
+            #[cfg(feature = "check-core")]
+            Error::InvalidChecksumRange { .. } => false,
+            Error::MissingPrefix => true,
+            Error::UnalignedLength { .. } => true,
+            Error::WrongLength { .. } => true,
+            Error::InvalidCrc32 { .. } => true,
+            Error::InvalidCheckFnChecksum => true,
+            Error::WrongLeadingZeros { .. } => true,
+            Error::IntegerOverflow => true,
+            Error::IntegerZero => true,
+            Error::InvalidUuidLength { .. } => true,
         }
     }
 }
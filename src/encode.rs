@@ -1,22 +1,31 @@
 //! Functions for encoding into Base58 encoded strings.
 
-use core::fmt;
+use core::{fmt, marker::PhantomData};
 
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
 
-use crate::Check;
-#[cfg(any(feature = "check", feature = "cb58"))]
-use crate::CHECKSUM_LEN;
+#[cfg(feature = "check-core")]
+use crate::Version;
+use crate::{Check, MAX_CHECK_FN_LEN};
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+use crate::{Sha256, Sha256Fn, CHECKSUM_LEN};
 
-use crate::Alphabet;
+use crate::{alphabet::StaticAlphabet, Alphabet, DefaultSha256};
 
 /// A builder for setting up the alphabet and output of a base58 encode.
+///
+/// `H` is the SHA-256 implementation used to calculate checksums, see
+/// [`DefaultSha256`].
 #[allow(missing_debug_implementations)]
-pub struct EncodeBuilder<'a, I: AsRef<[u8]>> {
+pub struct EncodeBuilder<'a, I: AsRef<[u8]>, H = DefaultSha256> {
     input: I,
     alpha: &'a Alphabet,
-    check: Check,
+    check: Check<'a>,
+    padded_to: Option<usize>,
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+    sha256: Sha256Fn,
+    hasher: PhantomData<fn() -> H>,
 }
 
 /// A specialized [`Result`](core::result::Result) type for [`bs58::encode`](module@crate::encode)
@@ -28,6 +37,13 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub enum Error {
     /// The output buffer was too small to contain the entire input.
     BufferTooSmall,
+
+    /// The input to [`bs58::encode_hex`](crate::encode_hex) contained a character that was not a
+    /// hex digit, or was missing the final digit of a byte.
+    InvalidHex {
+        /// The (byte) index in the input string of the invalid or missing digit.
+        index: usize,
+    },
 }
 
 /// Represents a buffer that can be encoded into. See [`EncodeBuilder::onto`] and the provided
@@ -41,6 +57,11 @@ pub trait EncodeTarget {
         max_len: usize,
         f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
     ) -> Result<usize>;
+
+    /// Removes any existing data from a resizable buffer, called before encoding by
+    /// [`EncodeBuilder::overwrite`]. Fixed size buffers are always written from the start, so by
+    /// default this does nothing.
+    fn clear(&mut self) {}
 }
 
 impl<T: EncodeTarget + ?Sized> EncodeTarget for &mut T {
@@ -51,6 +72,10 @@ impl<T: EncodeTarget + ?Sized> EncodeTarget for &mut T {
     ) -> Result<usize> {
         T::encode_with(self, max_len, f)
     }
+
+    fn clear(&mut self) {
+        T::clear(self)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -66,6 +91,10 @@ impl EncodeTarget for Vec<u8> {
         self.truncate(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        Vec::clear(self)
+    }
 }
 
 #[cfg(feature = "smallvec")]
@@ -88,6 +117,10 @@ impl<A: smallvec::Array<Item = u8>> EncodeTarget for smallvec::SmallVec<A> {
         self.truncate(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        smallvec::SmallVec::clear(self)
+    }
 }
 
 #[cfg(feature = "tinyvec")]
@@ -103,6 +136,10 @@ impl<A: tinyvec::Array<Item = u8>> EncodeTarget for tinyvec::ArrayVec<A> {
         self.set_len(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        tinyvec::ArrayVec::clear(self)
+    }
 }
 
 #[cfg(feature = "tinyvec")]
@@ -118,6 +155,10 @@ impl EncodeTarget for tinyvec::SliceVec<'_, u8> {
         self.set_len(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        tinyvec::SliceVec::clear(self)
+    }
 }
 
 #[cfg(all(feature = "tinyvec", feature = "alloc"))]
@@ -133,6 +174,10 @@ impl<A: tinyvec::Array<Item = u8>> EncodeTarget for tinyvec::TinyVec<A> {
         self.truncate(original + len);
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        tinyvec::TinyVec::clear(self)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -147,6 +192,10 @@ impl EncodeTarget for String {
         *self = String::from_utf8(output).unwrap();
         Ok(len)
     }
+
+    fn clear(&mut self) {
+        String::clear(self)
+    }
 }
 
 impl EncodeTarget for [u8] {
@@ -176,6 +225,9 @@ impl EncodeTarget for str {
                         Ok(_) => return,
                         Err(e) => {
                             index += e.valid_up_to();
+                            // The remnants of a partially overwritten character are continuation
+                            // bytes, each reported as its own invalid sequence, or an incomplete
+                            // sequence at the very end of the buffer
                             if let Some(len) = e.error_len() {
                                 for i in &mut self.0[index..index + len] {
                                     *i = 0;
@@ -207,23 +259,33 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
     /// Setup encoder for the given string using the given alphabet.
     /// Preferably use [`bs58::encode`](crate::encode()) instead of this
     /// directly.
-    pub fn new(input: I, alpha: &'a Alphabet) -> EncodeBuilder<'a, I> {
+    pub const fn new(input: I, alpha: &'a Alphabet) -> EncodeBuilder<'a, I> {
         EncodeBuilder {
             input,
             alpha,
             check: Check::Disabled,
+            padded_to: None,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            sha256: crate::default_sha256,
+            hasher: PhantomData,
         }
     }
 
     /// Setup encoder for the given string using default prepared alphabet.
-    pub(crate) fn from_input(input: I) -> EncodeBuilder<'static, I> {
+    pub(crate) const fn from_input(input: I) -> EncodeBuilder<'static, I> {
         EncodeBuilder {
             input,
             alpha: Alphabet::DEFAULT,
             check: Check::Disabled,
+            padded_to: None,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            sha256: crate::default_sha256,
+            hasher: PhantomData,
         }
     }
+}
 
+impl<'a, I: AsRef<[u8]>, H> EncodeBuilder<'a, I, H> {
     /// Change the alphabet that will be used for encoding.
     ///
     /// # Examples
@@ -236,8 +298,9 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
     ///         .with_alphabet(bs58::Alphabet::RIPPLE)
     ///         .into_string());
     /// ```
-    pub fn with_alphabet(self, alpha: &'a Alphabet) -> EncodeBuilder<'a, I> {
-        EncodeBuilder { alpha, ..self }
+    pub const fn with_alphabet(mut self, alpha: &'a Alphabet) -> EncodeBuilder<'a, I, H> {
+        self.alpha = alpha;
+        self
     }
 
     /// Include checksum calculated using the [Base58Check][] algorithm when
@@ -255,9 +318,12 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
     ///         .with_check()
     ///         .into_string());
     /// ```
-    #[cfg(feature = "check")]
-    pub fn with_check(self) -> EncodeBuilder<'a, I> {
-        let check = Check::Enabled(None);
+    #[cfg(feature = "check-core")]
+    pub fn with_check(self) -> EncodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(None.into());
         EncodeBuilder { check, ..self }
     }
 
@@ -276,12 +342,176 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
     ///         .with_check_version(42)
     ///         .into_string());
     /// ```
-    #[cfg(feature = "check")]
-    pub fn with_check_version(self, expected_ver: u8) -> EncodeBuilder<'a, I> {
-        let check = Check::Enabled(Some(expected_ver));
+    #[cfg(feature = "check-core")]
+    pub fn with_check_version(self, expected_ver: u8) -> EncodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(Some(expected_ver).into());
         EncodeBuilder { check, ..self }
     }
 
+    /// Include checksum calculated using the [Base58Check][] algorithm and a
+    /// multi-byte version prefix when encoding, as used by formats such as
+    /// [BIP32][] extended keys.
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    /// [BIP32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78];
+    /// assert_eq!(
+    ///     "oP8aA4HEEyFxxYhp",
+    ///     bs58::encode(input)
+    ///         .with_check_version_bytes(&[42])
+    ///         .into_string());
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_check_version_bytes(self, version: &'a [u8]) -> EncodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(Version::Bytes(version));
+        EncodeBuilder { check, ..self }
+    }
+
+    /// Include checksum calculated using the [Base58Check][] algorithm with a network byte
+    /// followed by a version byte as the prefix, i.e. `network || version || payload ||
+    /// checksum`, as used by some token standards.
+    ///
+    /// Decode with [`DecodeBuilder::into_check_network_version`](crate::decode::DecodeBuilder::into_check_network_version).
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78];
+    /// assert_eq!(
+    ///     "6daR2mjseBoDRR4Sf",
+    ///     bs58::encode(input)
+    ///         .with_network_version(1, 42)
+    ///         .into_string());
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_network_version(self, network: u8, version: u8) -> EncodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::Enabled(Version::Pair([network, version]));
+        EncodeBuilder { check, ..self }
+    }
+
+    /// Calculate the exact length of the encoding of the input, including the checksum and any
+    /// version or padding set on the builder, without encoding it, e.g. to lay out space for it.
+    ///
+    /// As base58 encodes its input as a single number the length depends on its value, so this
+    /// calculates the checksum and counts the digits of the value in a buffer on the stack. Use
+    /// [`Self::max_check_encoded_len`] for a bound from the input length alone.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::BufferTooSmall`] if, after any leading zero bytes, the input along with the
+    /// version and checksum is longer than 1024 bytes, or the encoding is longer than the width
+    /// set by [`Self::padded_to`], as [`Self::onto`] would return.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() -> Result<(), bs58::encode::Error> {
+    /// let input = [0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78];
+    /// assert_eq!(10, bs58::encode(input).check_encoded_len()?);
+    /// assert_eq!(15, bs58::encode(input).with_check().check_encoded_len()?);
+    /// assert_eq!(16, bs58::encode(input).with_check_version(42).check_encoded_len()?);
+    /// assert_eq!(20, bs58::encode(input).with_check().padded_to(20).check_encoded_len()?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn check_encoded_len(&self) -> Result<usize> {
+        let input = self.input.as_ref();
+        let len = match &self.check {
+            Check::Disabled => exact_encoded_len(input)?,
+            Check::Enabled(version) => {
+                let version = version.as_bytes();
+                let checksum = check_checksum(input, version, self.sha256);
+                exact_encoded_len(version.iter().chain(input).chain(&checksum))?
+            }
+            #[cfg(feature = "cb58-core")]
+            Check::CB58(version) => {
+                let version = version.as_bytes();
+                let checksum = cb58_checksum(input, version, self.sha256);
+                exact_encoded_len(version.iter().chain(input).chain(&checksum))?
+            }
+            Check::Delimited(_) => {
+                // Only the payload segment is padded
+                let checksum = check_checksum(input, &[], self.sha256);
+                let len = self.padded_len(exact_encoded_len(input)?)?;
+                return Ok(len + 1 + exact_encoded_len(&checksum)?);
+            }
+            Check::Custom { len, f } => {
+                let mut checksum = [0; MAX_CHECK_FN_LEN];
+                let checksum = &mut checksum[..*len];
+                f(input, checksum);
+                exact_encoded_len(input.iter().chain(&*checksum))?
+            }
+        };
+        self.padded_len(len)
+    }
+
+    /// Calculate the longest the encoding of the input can be, including the checksum and any
+    /// version or padding set on the builder, from the input length alone without hashing it.
+    ///
+    /// Leading zero bytes each encode to one character and the remaining bytes, including the
+    /// checksum, are counted as their longest encoding from
+    /// [`char_len_range`](crate::char_len_range), so the actual encoding can be a character or two
+    /// shorter depending on the value. Use [`Self::check_encoded_len`] for the exact length.
+    ///
+    /// # Panics
+    ///
+    /// If the result would overflow a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() {
+    /// let input = [0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78];
+    /// assert_eq!(10, bs58::encode(input).max_check_encoded_len());
+    /// assert_eq!(16, bs58::encode(input).with_check().max_check_encoded_len());
+    /// assert_eq!(15, bs58::encode(input).with_check().into_string().len());
+    /// assert_eq!(20, bs58::encode(input).with_check().padded_to(20).max_check_encoded_len());
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn max_check_encoded_len(&self) -> usize {
+        let input = self.input.as_ref();
+        let (version, check_len, suffix_len) = match &self.check {
+            Check::Disabled => (&[][..], 0, 0),
+            Check::Enabled(version) => (version.as_bytes(), CHECKSUM_LEN, 0),
+            #[cfg(feature = "cb58-core")]
+            Check::CB58(version) => (version.as_bytes(), CHECKSUM_LEN, 0),
+            // The checksum is encoded after the delimiter, separately from the padded payload
+            Check::Delimited(_) => (&[][..], 0, 1 + crate::char_len_range(CHECKSUM_LEN).1),
+            Check::Custom { len, .. } => (&[][..], *len, 0),
+        };
+        let zeros = version
+            .iter()
+            .chain(input)
+            .take_while(|&&byte| byte == 0)
+            .count();
+        let significant = version.len() + input.len() + check_len - zeros;
+        let len = zeros + crate::char_len_range(significant).1;
+        len.max(self.padded_to.unwrap_or(0)) + suffix_len
+    }
+
     /// Include checksum calculated using the [CB58][] algorithm and
     /// version (if specified) when encoding.
     ///
@@ -297,12 +527,133 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
     ///         .as_cb58(Some(42))
     ///         .into_string());
     /// ```
-    #[cfg(feature = "cb58")]
-    pub fn as_cb58(self, expected_ver: Option<u8>) -> EncodeBuilder<'a, I> {
-        let check = Check::CB58(expected_ver);
+    #[cfg(feature = "cb58-core")]
+    pub fn as_cb58(self, expected_ver: Option<u8>) -> EncodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        let check = Check::CB58(expected_ver.into());
+        EncodeBuilder { check, ..self }
+    }
+
+    /// Append a checksum calculated by the given function before encoding, for integrity schemes
+    /// other than [Base58Check][] and [CB58][], such as a CRC.
+    ///
+    /// The encoded bytes are `payload || checksum`, where `checksum` is the `N` bytes written by
+    /// `f(payload, checksum)`. There is no version byte, include one in the payload if needed.
+    /// Checksums are limited to 64 bytes, a longer `N` fails to compile.
+    /// Verify with
+    /// [`DecodeBuilder::with_check_fn`](crate::decode::DecodeBuilder::with_check_fn).
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    /// [CB58]: https://support.avax.network/en/articles/4587395-what-is-cb58
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // A one byte checksum xoring the payload together
+    /// let xor = |payload: &[u8], checksum: &mut [u8]| {
+    ///     checksum[0] = payload.iter().fold(0, |acc, b| acc ^ b);
+    /// };
+    ///
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!(
+    ///     bs58::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58, 0xbe]).into_string(),
+    ///     bs58::encode(input).with_check_fn::<1>(&xor).into_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// let zero = |_: &[u8], _: &mut [u8]| {};
+    /// bs58::encode([0x61]).with_check_fn::<65>(&zero).into_string();
+    /// ```
+    pub fn with_check_fn<const N: usize>(
+        self,
+        f: &'a dyn Fn(&[u8], &mut [u8]),
+    ) -> EncodeBuilder<'a, I, H> {
+        const { assert!(N <= MAX_CHECK_FN_LEN, "checksums are limited to 64 bytes") };
+        let check = Check::Custom { len: N, f };
+        EncodeBuilder { check, ..self }
+    }
+
+    /// Append a [Base58Check][] checksum as a separate segment after `delimiter`, i.e.
+    /// `base58(payload) || delimiter || base58(checksum)`, so that the payload and checksum can
+    /// be decoded independently, as preferred by some human-readable and QR-code formats.
+    ///
+    /// The checksum is the first 4 bytes of the double SHA-256 of the payload, without a version
+    /// byte. Any padding from [`Self::padded_to`] only applies to the payload segment. Verify with
+    /// [`DecodeBuilder::with_check_delimited`](crate::decode::DecodeBuilder::with_check_delimited).
+    ///
+    /// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+    ///
+    /// # Panics
+    ///
+    /// If `delimiter` is not ASCII. It should also not be part of the alphabet, otherwise the
+    /// encoding can't be split unambiguously.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!(
+    ///     "he11owor1d.45W3Aw",
+    ///     bs58::encode(input).with_check_delimited('.').into_string());
+    /// ```
+    #[cfg(feature = "check-core")]
+    pub fn with_check_delimited(self, delimiter: char) -> EncodeBuilder<'a, I, H>
+    where
+        H: Sha256,
+    {
+        assert!(delimiter.is_ascii(), "checksum delimiter must be ASCII");
+        let check = Check::Delimited(delimiter as u8);
         EncodeBuilder { check, ..self }
     }
 
+    /// Use the given SHA-256 implementation to calculate checksums, instead of the default
+    /// implementation from the `sha2` crate.
+    ///
+    /// Without the `sha2` feature this must be called before any checked mode can be enabled.
+    /// See [`Sha256`] for more details and an example.
+    #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+    pub fn with_sha256<S: Sha256>(self) -> EncodeBuilder<'a, I, S> {
+        EncodeBuilder {
+            input: self.input,
+            alpha: self.alpha,
+            check: self.check,
+            padded_to: self.padded_to,
+            sha256: S::digest,
+            hasher: PhantomData,
+        }
+    }
+
+    /// Pad the encoded string to exactly `width` characters by prefixing it with the zero
+    /// character of the alphabet, e.g. for fixed-width keys.
+    ///
+    /// Padding characters decode as leading zero bytes, use
+    /// [`DecodeBuilder::padded`](crate::decode::DecodeBuilder::padded) to strip them when
+    /// decoding. With the default alphabet, padded strings sort in the same order as the values
+    /// they encode.
+    ///
+    /// If the encoded string is longer than `width` [`Error::BufferTooSmall`] is returned, the
+    /// infallible methods such as [`Self::into_string`] will panic instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!("11he11owor1d", bs58::encode(input).padded_to(12).into_string());
+    ///
+    /// let mut output = String::new();
+    /// assert_eq!(
+    ///     bs58::encode::Error::BufferTooSmall,
+    ///     bs58::encode(input).padded_to(8).onto(&mut output).unwrap_err());
+    /// ```
+    pub fn padded_to(self, width: usize) -> EncodeBuilder<'a, I, H> {
+        EncodeBuilder {
+            padded_to: Some(width),
+            ..self
+        }
+    }
+
     /// Encode into a new owned string.
     ///
     /// # Examples
@@ -318,8 +669,148 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
         output
     }
 
+    /// Encode into the given string, replacing its contents and reusing its allocation, and
+    /// return a view of the encoded output.
+    ///
+    /// See the documentation for [`Self::onto`] for the errors that can be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = "goodbye world".to_owned();
+    /// let encoded = bs58::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+    ///     .into_str(&mut output)?;
+    /// assert_eq!("he11owor1d", encoded);
+    ///
+    /// assert_eq!(
+    ///     bs58::encode::Error::BufferTooSmall,
+    ///     bs58::encode([0xff; 8]).padded_to(4).into_str(&mut output).unwrap_err());
+    /// # Ok::<(), bs58::encode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::needless_lifetimes)] // spelled out to show the result borrows `output`
+    pub fn into_str<'s>(self, output: &'s mut String) -> Result<&'s str> {
+        self.overwrite(&mut *output)?;
+        Ok(output)
+    }
+
+    /// Encode into a new owned string, also returning the length of the input bytes, for storing
+    /// alongside the encoding, e.g. as a database index. The length can be verified when
+    /// decoding with [`DecodeBuilder::expect_len`](crate::decode::DecodeBuilder::expect_len).
+    ///
+    /// The length is of the input only, excluding any separate version bytes or checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!(("he11owor1d".to_owned(), 8), bs58::encode(input).into_indexed());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_indexed(self) -> (String, usize) {
+        let len = self.input.as_ref().len();
+        (self.into_string(), len)
+    }
+
+    /// Encode into a new owned string containing only the first `len` characters of the
+    /// encoding, e.g. to display an abbreviated key.
+    ///
+    /// Every character of a base58 encoding depends on the whole input, as carries from the least
+    /// significant bytes can reach the most significant digits, so this still performs the full
+    /// encoding and only saves the caller truncating the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!("he11o", bs58::encode(input).into_truncated_string(5));
+    /// assert_eq!("he11owor1d", bs58::encode(input).into_truncated_string(20));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_truncated_string(self, len: usize) -> String {
+        let mut output = self.into_string();
+        // The encoding is ASCII, so any length is a char boundary
+        output.truncate(len);
+        output
+    }
+
+    /// Encode into a new owned string, also returning the checksum that was included in it.
+    ///
+    /// For [`Self::with_check_delimited`] this is the checksum encoded after the delimiter. The
+    /// checksum is `None` if none was configured with [`Self::with_check`] or similar, or if a
+    /// custom checksum was configured with [`Self::with_check_fn`], which can have any length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() {
+    /// let input = [0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78];
+    /// assert_eq!(
+    ///     ("QuT57JNzzWTu7mW".to_owned(), Some([0xf5, 0x3b, 0xf1, 0x8d])),
+    ///     bs58::encode(input)
+    ///         .with_check()
+    ///         .into_string_and_checksum());
+    ///
+    /// assert_eq!(
+    ///     ("StV1DL6CwTryKyV".to_owned(), None),
+    ///     bs58::encode(b"hello world").into_string_and_checksum());
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(all(any(feature = "check-core", feature = "cb58-core"), feature = "alloc"))]
+    pub fn into_string_and_checksum(self) -> (String, Option<[u8; CHECKSUM_LEN]>) {
+        let input = self.input.as_ref();
+        let (version, checksum) = match &self.check {
+            Check::Disabled | Check::Custom { .. } => return (self.into_string(), None),
+            #[cfg(feature = "check-core")]
+            Check::Enabled(version) => (
+                version.as_bytes(),
+                check_checksum(input, version.as_bytes(), self.sha256),
+            ),
+            #[cfg(feature = "cb58-core")]
+            Check::CB58(version) => (
+                version.as_bytes(),
+                cb58_checksum(input, version.as_bytes(), self.sha256),
+            ),
+            #[cfg(feature = "check-core")]
+            Check::Delimited(delimiter) => {
+                // The same checksum as Base58Check without a version, encoded after the delimiter
+                let delimiter = char::from(*delimiter);
+                let checksum = check_checksum(input, &[], self.sha256);
+                let payload = EncodeBuilder {
+                    check: Check::Disabled,
+                    ..self
+                };
+                let mut output = payload.into_string();
+                output.push(delimiter);
+                EncodeBuilder::new(&checksum, self.alpha)
+                    .onto(&mut output)
+                    .unwrap();
+                return (output, Some(checksum));
+            }
+        };
+        let mut output = String::new();
+        let input_len = input.len().saturating_add(CHECKSUM_LEN + version.len());
+        output
+            .encode_with(self.max_len(input_len).unwrap(), |output| {
+                let len = encode_into(
+                    version.iter().chain(input.iter()).chain(checksum.iter()),
+                    output,
+                    self.alpha,
+                )?;
+                self.pad(output, len)
+            })
+            .unwrap();
+        (output, Some(checksum))
+    }
+
     /// Encode into a new owned vector.
     ///
+    /// The output is guaranteed to only contain ASCII bytes taken from the alphabet, so it can be
+    /// passed on as UTF-8 without re-validating it.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -333,17 +824,117 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
         output
     }
 
+    /// Encode into an iterator over the characters of the encoded string, in display order (most
+    /// significant first), e.g. for feeding a character based sink.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut chars = bs58::encode(input).into_char_iter();
+    /// assert_eq!(Some('h'), chars.next());
+    /// assert_eq!("e11owor1d", chars.collect::<String>());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_char_iter(self) -> impl Iterator<Item = char> {
+        self.into_vec().into_iter().map(char::from)
+    }
+
+    /// Encode into a new owned vector, prefixed by the length of the encoded string as a
+    /// big-endian `u32`, for writing records that can be read back with
+    /// [`DecodeBuilder::into_length_prefixed_vec`](crate::decode::DecodeBuilder::into_length_prefixed_vec).
+    ///
+    /// # Panics
+    ///
+    /// If the encoded string is longer than [`u32::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!(
+    ///     b"\0\0\0\x0ahe11owor1d",
+    ///     &*bs58::encode(input).into_length_prefixed_vec());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_length_prefixed_vec(self) -> Vec<u8> {
+        let mut output = Vec::from([0; 4]);
+        let len = self.onto(&mut output).unwrap();
+        let len = u32::try_from(len).expect("encoded string is too long for a u32 length prefix");
+        output[..4].copy_from_slice(&len.to_be_bytes());
+        output
+    }
+
+    /// Encode into a new owned vector, then setup another encoder with the same alphabet using
+    /// that as its input, for schemes that apply base58 multiple times.
+    ///
+    /// Any checksum is only applied to this encoding, not the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let encoded = bs58::encode(input).reencode().into_string();
+    /// assert_eq!("6sBFcNXUTq9GY3", encoded);
+    ///
+    /// let decoded = bs58::decode(bs58::decode(encoded).into_vec()?).into_vec()?;
+    /// assert_eq!(input, *decoded);
+    /// # Ok::<(), bs58::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn reencode(self) -> EncodeBuilder<'a, Vec<u8>, H> {
+        let alpha = self.alpha;
+        #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+        let sha256 = self.sha256;
+        EncodeBuilder {
+            input: self.into_vec(),
+            alpha,
+            check: Check::Disabled,
+            padded_to: None,
+            #[cfg(any(feature = "check-core", feature = "cb58-core"))]
+            sha256,
+            hasher: PhantomData,
+        }
+    }
+
+    /// Encode onto the given [`fmt::Write`] via a stack buffer of `N` bytes, without allocating.
+    ///
+    /// Returns [`fmt::Error`] if the encoded string would not fit within `N` bytes. The
+    /// [`bs58::write_base58!`](crate::write_base58!) macro provides a more convenient way to call this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = "goodbye world ".to_owned();
+    /// bs58::encode(input).onto_fmt::<10>(&mut output)?;
+    /// assert_eq!("goodbye world he11owor1d", output);
+    ///
+    /// assert!(bs58::encode(input).onto_fmt::<9>(&mut output).is_err());
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn onto_fmt<const N: usize>(self, output: &mut (impl fmt::Write + ?Sized)) -> fmt::Result {
+        let mut buffer = [0; N];
+        let len = self.onto(&mut buffer[..]).map_err(|_| fmt::Error)?;
+        output.write_str(core::str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?)
+    }
+
     /// Encode onto the given buffer.
     ///
     /// Returns the length written onto the buffer.
     ///
     /// If the buffer is resizeable it will be extended and the new data will be written to the end
-    /// of it, otherwise the data will be overwritten from the start.
+    /// of it, otherwise the data will be overwritten from the start. Use [`Self::overwrite`] to
+    /// replace the contents of a resizeable buffer instead.
     ///
     /// If the buffer is not resizeable bytes after the final character will be left alone, except
     /// up to 3 null bytes may be written to an `&mut str` to overwrite remaining characters of a
     /// partially overwritten multi-byte character.
     ///
+    /// The bytes written are always ASCII characters from the alphabet. When building up ASCII
+    /// output prefer encoding onto a `Vec<u8>` over a `String`, the latter has to re-validate the
+    /// buffer as UTF-8 after every encode.
+    ///
     /// See the documentation for [`bs58::encode`](crate::encode()) for an
     /// explanation of the errors that may occur.
     ///
@@ -401,37 +992,279 @@ impl<'a, I: AsRef<[u8]>> EncodeBuilder<'a, I> {
     pub fn onto(self, mut output: impl EncodeTarget) -> Result<usize> {
         let input = self.input.as_ref();
         match self.check {
-            Check::Disabled => output.encode_with(max_encoded_len(input.len()), |output| {
-                encode_into(input, output, self.alpha)
+            Check::Disabled => output.encode_with(self.max_len(input.len())?, |output| {
+                let len = encode_into(input, output, self.alpha)?;
+                self.pad(output, len)
             }),
-            #[cfg(feature = "check")]
+            #[cfg(feature = "check-core")]
             Check::Enabled(version) => {
-                let input_len = input.len() + CHECKSUM_LEN + version.map_or(0, |_| 1);
-                output.encode_with(max_encoded_len(input_len), |output| {
-                    encode_check_into(self.input.as_ref(), output, self.alpha, version)
+                let version = version.as_bytes();
+                let input_len = input.len().saturating_add(CHECKSUM_LEN + version.len());
+                output.encode_with(self.max_len(input_len)?, |output| {
+                    let len = encode_check_into(input, output, self.alpha, version, self.sha256)?;
+                    self.pad(output, len)
                 })
             }
-            #[cfg(feature = "cb58")]
+            #[cfg(feature = "cb58-core")]
             Check::CB58(version) => {
-                let input_len = input.len() + CHECKSUM_LEN + version.map_or(0, |_| 1);
-                output.encode_with(max_encoded_len(input_len), |output| {
-                    encode_cb58_into(self.input.as_ref(), output, self.alpha, version)
+                let version = version.as_bytes();
+                let input_len = input.len().saturating_add(CHECKSUM_LEN + version.len());
+                output.encode_with(self.max_len(input_len)?, |output| {
+                    let len = encode_cb58_into(input, output, self.alpha, version, self.sha256)?;
+                    self.pad(output, len)
+                })
+            }
+            #[cfg(feature = "check-core")]
+            Check::Delimited(delimiter) => {
+                let checksum = check_checksum(input, &[], self.sha256);
+                let max_len =
+                    self.max_len(input.len())?.saturating_add(1) + max_encoded_len(CHECKSUM_LEN)?;
+                output.encode_with(max_len, |output| {
+                    let len = encode_into(input, output, self.alpha)?;
+                    let len = self.pad(output, len)?;
+                    let (separator, rest) = output[len..]
+                        .split_first_mut()
+                        .ok_or(Error::BufferTooSmall)?;
+                    *separator = delimiter;
+                    Ok(len + 1 + encode_into(&checksum, rest, self.alpha)?)
+                })
+            }
+            Check::Custom { len, f } => {
+                let mut checksum = [0; MAX_CHECK_FN_LEN];
+                let checksum = &mut checksum[..len];
+                f(input, checksum);
+                output.encode_with(self.max_len(input.len().saturating_add(len))?, |output| {
+                    let len = encode_into(input.iter().chain(checksum.iter()), output, self.alpha)?;
+                    self.pad(output, len)
                 })
             }
         }
     }
+
+    /// Encode onto the given buffer, first clearing any existing contents of a resizeable buffer
+    /// so that its allocation is reused, e.g. when encoding many values in a loop.
+    ///
+    /// Returns the length written onto the buffer. Other than the clearing this behaves the same
+    /// as [`Self::onto`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = String::new();
+    /// for input in [[0x04, 0x30, 0x5e, 0x2b], [0x24, 0x73, 0xf0, 0x58]] {
+    ///     bs58::encode(input).overwrite(&mut output)?;
+    ///     println!("{}", output);
+    /// }
+    /// assert_eq!("w3VpK", output);
+    /// # Ok::<(), bs58::encode::Error>(())
+    /// ```
+    pub fn overwrite(self, mut output: impl EncodeTarget) -> Result<usize> {
+        output.clear();
+        self.onto(output)
+    }
+
+    /// Encode into the given buffer, returning the encoded ASCII bytes as a subslice of it.
+    ///
+    /// This is the same as [`Self::onto`] with a `&mut [u8]`, but saves reslicing the buffer
+    /// with the returned length.
+    ///
+    /// See the documentation for [`bs58::encode`](crate::encode()) for an explanation of the
+    /// errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = [0xFF; 16];
+    /// assert_eq!(b"he11owor1d", bs58::encode(input).into_slice(&mut output)?);
+    /// assert_eq!(
+    ///     bs58::encode::Error::BufferTooSmall,
+    ///     bs58::encode(input).into_slice(&mut output[..9]).unwrap_err());
+    /// # Ok::<(), bs58::encode::Error>(())
+    /// ```
+    pub fn into_slice(self, output: &mut [u8]) -> Result<&[u8]> {
+        let len = self.onto(&mut *output)?;
+        Ok(&output[..len])
+    }
+
+    /// The maximum length of the output, given the length of the input including any version and
+    /// checksum bytes.
+    fn max_len(&self, input_len: usize) -> Result<usize> {
+        Ok(max_encoded_len(input_len)?.max(self.padded_to.unwrap_or(0)))
+    }
+
+    /// Move the `len` encoded characters at the start of `output` to the end of the padded width,
+    /// filling the start with the zero character, returning the new length.
+    /// The length of an encoding of `len` characters once padded, as written by [`Self::pad`].
+    #[cfg(feature = "check-core")]
+    fn padded_len(&self, len: usize) -> Result<usize> {
+        match self.padded_to {
+            Some(width) if len > width => Err(Error::BufferTooSmall),
+            Some(width) => Ok(width),
+            None => Ok(len),
+        }
+    }
+
+    fn pad(&self, output: &mut [u8], len: usize) -> Result<usize> {
+        let Some(width) = self.padded_to else {
+            return Ok(len);
+        };
+        if len > width || width > output.len() {
+            return Err(Error::BufferTooSmall);
+        }
+        output.copy_within(..len, width - len);
+        output[..width - len].fill(self.alpha.encode[0]);
+        Ok(width)
+    }
+}
+
+/// For `const` compatibility we are restricted to using a concrete input and output type, as
+/// `const` trait implementations and `&mut` are unstable. These methods will eventually be
+/// deprecated once the primary interfaces can be converted into `const fn` directly.
+impl<'a, 'b, H> EncodeBuilder<'a, &'b [u8], H> {
+    /// Encode into a new array.
+    ///
+    /// Returns the encoded array as ASCII bytes, any bytes after the final encoded character are
+    /// left as zero.
+    ///
+    /// See the documentation for [`bs58::encode`](crate::encode())
+    /// for an explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// const _: () = {
+    ///     let Ok(output) = bs58::encode(b"world".as_slice()).into_array_const::<7>() else {
+    ///         panic!()
+    ///     };
+    ///     assert!(matches!(&output, b"EUYUqQf"));
+    /// };
+    /// ```
+    pub const fn into_array_const<const N: usize>(self) -> Result<[u8; N]> {
+        assert!(
+            matches!(self.check, Check::Disabled),
+            "checksums in const aren't supported (why are you using this API at runtime)",
+        );
+        assert!(
+            self.padded_to.is_none(),
+            "padding in const isn't supported (why are you using this API at runtime)",
+        );
+        encode_into_const(self.input, self.alpha)
+    }
+
+    /// [`Self::into_array_const`] but the result will be unwrapped, turning any error into a panic
+    /// message via [`Error::unwrap_const`], as a simple `into_array_const().unwrap()` isn't
+    /// possible yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// const _: () = {
+    ///     let output: [u8; 7] = bs58::encode(b"world".as_slice()).into_array_const_unwrap();
+    ///     assert!(matches!(&output, b"EUYUqQf"));
+    /// };
+    /// ```
+    ///
+    /// ```rust
+    /// const _: () = {
+    ///     let output: [u8; 10] = bs58::encode([0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78].as_slice())
+    ///         .with_alphabet(bs58::Alphabet::RIPPLE)
+    ///         .into_array_const_unwrap();
+    ///     assert!(matches!(&output, b"he11owor1d"));
+    /// };
+    /// ```
+    pub const fn into_array_const_unwrap<const N: usize>(self) -> [u8; N] {
+        match self.into_array_const() {
+            Ok(result) => result,
+            Err(err) => err.unwrap_const(),
+        }
+    }
+
+    /// [`Self::into_array_const_unwrap`] but additionally asserting that the encoding is exactly
+    /// `N` characters long, so that a `const` array or string sized for the encoding is verified
+    /// at compile time instead of silently containing trailing zero bytes.
+    ///
+    /// # Panics
+    ///
+    /// If the encoding is longer or shorter than `N` characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// const ENCODED: [u8; 7] = bs58::encode(b"world".as_slice()).into_array_const_exact();
+    /// assert_eq!(b"EUYUqQf", &ENCODED);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// // Fails to compile, the encoding is only 7 characters long
+    /// const ENCODED: [u8; 8] = bs58::encode(b"world".as_slice()).into_array_const_exact();
+    /// # let _ = ENCODED;
+    /// ```
+    pub const fn into_array_const_exact<const N: usize>(self) -> [u8; N] {
+        let output = match self.into_array_const::<N>() {
+            Ok(output) => output,
+            Err(_) => panic!("encoded output is longer than the given array length"),
+        };
+        // Encoded characters are never zero, so a trailing zero is unused space
+        if N > 0 && output[N - 1] == 0 {
+            panic!("encoded output is shorter than the given array length");
+        }
+        output
+    }
 }
 
 /// Return maximum possible encoded length of a buffer with given length.
 ///
 /// Assumes that the `len` already includes version and checksum bytes if those
-/// are
-fn max_encoded_len(len: usize) -> usize {
+/// are. Returns [`Error::BufferTooSmall`] if the length can't be represented in a `usize`, which
+/// is possible for huge inputs on 32-bit targets.
+pub(crate) const fn max_encoded_len(len: usize) -> Result<usize> {
     // log_2(256) / log_2(58) ≈ 1.37.  Assume 1.5 for easier calculation.
-    len + (len + 1) / 2
+    match len.checked_add(len.div_ceil(2)) {
+        Some(max_len) => Ok(max_len),
+        None => Err(Error::BufferTooSmall),
+    }
 }
 
-fn encode_into<'a, I>(input: I, output: &mut [u8], alpha: &Alphabet) -> Result<usize>
+/// Encode `input` into `output` using the alphabet `A`, returning the encoded length.
+///
+/// Unlike [`EncodeBuilder`], which takes the alphabet as a runtime reference, this generates a
+/// copy of the encoder specialized to `A`, allowing the compiler to resolve the alphabet lookups
+/// at compile time. Prefer the builder unless benchmarks show a hot path benefits from this.
+///
+/// # Examples
+///
+/// ```rust
+/// use bs58::alphabet::{Bitcoin, Ripple};
+///
+/// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+/// let mut output = [0; 10];
+///
+/// let len = bs58::encode::encode_static_into::<Bitcoin>(&input, &mut output)?;
+/// assert_eq!(b"he11owor1d", &output[..len]);
+///
+/// let len = bs58::encode::encode_static_into::<Ripple>(&input, &mut output)?;
+/// assert_eq!(b"6erroAoird", &output[..len]);
+///
+/// assert_eq!(
+///     bs58::encode::Error::BufferTooSmall,
+///     bs58::encode::encode_static_into::<Bitcoin>(&input, &mut output[..9]).unwrap_err());
+/// # Ok::<(), bs58::encode::Error>(())
+/// ```
+pub fn encode_static_into<A: StaticAlphabet>(input: &[u8], output: &mut [u8]) -> Result<usize> {
+    encode_into_inline(input, output, A::ALPHABET)
+}
+
+pub(crate) fn encode_into<'a, I>(input: I, output: &mut [u8], alpha: &Alphabet) -> Result<usize>
+where
+    I: Clone + IntoIterator<Item = &'a u8>,
+{
+    encode_into_inline(input, output, alpha)
+}
+
+// Always inlined so that `encode_static_into` gets a copy with its alphabet known
+#[inline(always)]
+fn encode_into_inline<'a, I>(input: I, output: &mut [u8], alpha: &Alphabet) -> Result<usize>
 where
     I: Clone + IntoIterator<Item = &'a u8>,
 {
@@ -469,23 +1302,81 @@ where
     Ok(index)
 }
 
-#[cfg(feature = "check")]
+/// The number of `u32` limbs used by [`exact_encoded_len`] to hold the value of the input.
+#[cfg(feature = "check-core")]
+const LEN_LIMBS: usize = 256;
+
+/// The length of the encoding of `input` without writing it: each leading zero byte encodes to
+/// one character, then the remaining value is accumulated into little-endian limbs on the stack
+/// and its digits counted by dividing it by `58^5` until it is zero.
+#[cfg(feature = "check-core")]
+fn exact_encoded_len<'a>(input: impl IntoIterator<Item = &'a u8>) -> Result<usize> {
+    const DIVISOR: u64 = 58 * 58 * 58 * 58 * 58;
+
+    let mut input = input.into_iter().peekable();
+    let mut zeros = 0;
+    while input.next_if(|&&byte| byte == 0).is_some() {
+        zeros += 1;
+    }
+
+    let mut limbs = [0u32; LEN_LIMBS];
+    let mut len = 0;
+    for &byte in input {
+        let mut carry = u64::from(byte);
+        for limb in &mut limbs[..len] {
+            carry += u64::from(*limb) << 8;
+            *limb = carry as u32;
+            carry >>= 32;
+        }
+        if carry > 0 {
+            *limbs.get_mut(len).ok_or(Error::BufferTooSmall)? = carry as u32;
+            len += 1;
+        }
+    }
+
+    let mut digits = 0;
+    while len > 0 {
+        let mut remainder = 0;
+        for limb in limbs[..len].iter_mut().rev() {
+            let value = remainder << 32 | u64::from(*limb);
+            *limb = (value / DIVISOR) as u32;
+            remainder = value % DIVISOR;
+        }
+        while len > 0 && limbs[len - 1] == 0 {
+            len -= 1;
+        }
+        if len > 0 {
+            digits += 5;
+        } else {
+            // The most significant digits, the remainder is non-zero as the value was
+            while remainder > 0 {
+                digits += 1;
+                remainder /= 58;
+            }
+        }
+    }
+    Ok(zeros + digits)
+}
+
+#[cfg(feature = "check-core")]
+pub(crate) fn check_checksum(input: &[u8], version: &[u8], sha256: Sha256Fn) -> [u8; CHECKSUM_LEN] {
+    let first_hash = sha256(&[version, input]);
+    let second_hash = sha256(&[&first_hash]);
+
+    let mut checksum = [0; CHECKSUM_LEN];
+    checksum.copy_from_slice(&second_hash[0..CHECKSUM_LEN]);
+    checksum
+}
+
+#[cfg(feature = "check-core")]
 fn encode_check_into(
     input: &[u8],
     output: &mut [u8],
     alpha: &Alphabet,
-    version: Option<u8>,
+    version: &[u8],
+    sha256: Sha256Fn,
 ) -> Result<usize> {
-    use sha2::{Digest, Sha256};
-
-    let mut first_hash = Sha256::new();
-    if let Some(version) = version {
-        first_hash.update([version; 1]);
-    }
-    let first_hash = first_hash.chain_update(input).finalize();
-    let second_hash = Sha256::digest(first_hash);
-
-    let checksum = &second_hash[0..CHECKSUM_LEN];
+    let checksum = check_checksum(input, version, sha256);
 
     encode_into(
         version.iter().chain(input.iter()).chain(checksum.iter()),
@@ -494,22 +1385,24 @@ fn encode_check_into(
     )
 }
 
-#[cfg(feature = "cb58")]
+#[cfg(feature = "cb58-core")]
+pub(crate) fn cb58_checksum(input: &[u8], version: &[u8], sha256: Sha256Fn) -> [u8; CHECKSUM_LEN] {
+    let hash = sha256(&[version, input]);
+
+    let mut checksum = [0; CHECKSUM_LEN];
+    checksum.copy_from_slice(&hash[hash.len() - CHECKSUM_LEN..]);
+    checksum
+}
+
+#[cfg(feature = "cb58-core")]
 fn encode_cb58_into(
     input: &[u8],
     output: &mut [u8],
     alpha: &Alphabet,
-    version: Option<u8>,
+    version: &[u8],
+    sha256: Sha256Fn,
 ) -> Result<usize> {
-    use sha2::{Digest, Sha256};
-
-    let mut hash = Sha256::new();
-    if let Some(version) = version {
-        hash.update([version; 1]);
-    }
-    let hash = hash.chain_update(input).finalize();
-
-    let checksum = &hash[hash.len() - CHECKSUM_LEN..];
+    let checksum = cb58_checksum(input, version, sha256);
 
     encode_into(
         version.iter().chain(input.iter()).chain(checksum.iter()),
@@ -518,6 +1411,60 @@ fn encode_cb58_into(
     )
 }
 
+const fn encode_into_const<const N: usize>(input: &[u8], alpha: &Alphabet) -> Result<[u8; N]> {
+    let mut output = [0u8; N];
+    let mut index = 0;
+
+    let mut i = 0;
+    while i < input.len() {
+        let mut carry = input[i] as usize;
+        let mut j = 0;
+        while j < index {
+            carry += (output[j] as usize) << 8;
+            output[j] = (carry % 58) as u8;
+            carry /= 58;
+            j += 1;
+        }
+        while carry > 0 {
+            if index == output.len() {
+                return Err(Error::BufferTooSmall);
+            }
+            output[index] = (carry % 58) as u8;
+            index += 1;
+            carry /= 58;
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < input.len() && input[i] == 0 {
+        if index == output.len() {
+            return Err(Error::BufferTooSmall);
+        }
+        output[index] = 0;
+        index += 1;
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < index {
+        output[i] = alpha.encode[output[i] as usize];
+        i += 1;
+    }
+
+    // reverse
+    let mut i = 0;
+    let n = index / 2;
+    while i < n {
+        let x = output[i];
+        output[i] = output[index - 1 - i];
+        output[index - 1 - i] = x;
+        i += 1;
+    }
+
+    Ok(output)
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
@@ -528,6 +1475,42 @@ impl fmt::Display for Error {
                 f,
                 "buffer provided to encode base58 string into was too small"
             ),
+            Error::InvalidHex { index } => write!(
+                f,
+                "provided hex string contained an invalid or missing digit at byte {}",
+                index
+            ),
+        }
+    }
+}
+
+impl Error {
+    /// Panic with an error message based on this error. This cannot include any of the dynamic
+    /// content because formatting in `const` is not yet possible.
+    pub const fn unwrap_const(self) -> ! {
+        match self {
+            Error::BufferTooSmall => {
+                panic!("buffer provided to encode base58 string into was too small")
+            }
+            Error::InvalidHex { .. } => {
+                panic!("provided hex string contained an invalid or missing digit")
+            }
+        }
+    }
+
+    /// Whether this error was caused by the input, so could be shown to an end user to correct,
+    /// rather than by how the encoder was used, which indicates a bug in the application.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert!(bs58::encode_hex("04x0").unwrap_err().is_user_input_error());
+    /// assert!(!bs58::encode([1, 2, 3]).onto(&mut [0; 1][..]).unwrap_err().is_user_input_error());
+    /// ```
+    pub const fn is_user_input_error(&self) -> bool {
+        match self {
+            Error::BufferTooSmall => false,
+            Error::InvalidHex { .. } => true,
         }
     }
 }
@@ -0,0 +1,34 @@
+//! Minimal hex support for the conversion helpers, to avoid depending on a hex crate.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::encode::{Error, Result};
+
+/// Encode bytes as hex, with lowercase or uppercase digits.
+pub(crate) fn encode(bytes: &[u8], upper: bool) -> String {
+    let digits = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let mut output = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        output.push(char::from(digits[usize::from(byte >> 4)]));
+        output.push(char::from(digits[usize::from(byte & 0xf)]));
+    }
+    output
+}
+
+/// Decode a hex string with lowercase or uppercase digits.
+pub(crate) fn decode(hex: &[u8]) -> Result<Vec<u8>> {
+    let digit = |index: usize| match hex.get(index) {
+        Some(c @ b'0'..=b'9') => Ok(c - b'0'),
+        Some(c @ b'a'..=b'f') => Ok(c - b'a' + 10),
+        Some(c @ b'A'..=b'F') => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidHex { index }),
+    };
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| Ok(digit(i)? << 4 | digit(i + 1)?))
+        .collect()
+}
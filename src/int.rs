@@ -0,0 +1,98 @@
+//! Integer types and byte orders for [`encode_int`](crate::encode_int) and
+//! [`DecodeBuilder::into_int`](crate::decode::DecodeBuilder::into_int).
+//!
+//! Integers are converted to bytes before base58 encoding, so the same value encodes differently
+//! depending on the byte order used. **The default is [`Endianness::Big`]**, matching the
+//! Bitcoin convention and [`DecodeBuilder::into_u64`](crate::decode::DecodeBuilder::into_u64);
+//! use [`Endianness::Little`] only when interoperating with a system that expects it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bs58::int::Endianness;
+//!
+//! assert_eq!("5R", bs58::encode_int(0x0100_u16, Endianness::Big));
+//! assert_eq!("12", bs58::encode_int(0x0100_u16, Endianness::Little));
+//! assert_eq!(0x0100_u16, bs58::decode("12").into_int(Endianness::Little)?);
+//! # Ok::<(), bs58::decode::Error>(())
+//! ```
+
+/// The byte order an integer is converted to before encoding, and read in after decoding.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Endianness {
+    /// Most significant byte first, as used by Bitcoin. This is the default.
+    #[default]
+    Big,
+
+    /// Least significant byte first.
+    Little,
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type that can be used with [`encode_int`](crate::encode_int) and
+/// [`DecodeBuilder::into_int`](crate::decode::DecodeBuilder::into_int), its word size
+/// determining the maximum decoded length.
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64` and `u128`.
+pub trait Int: private::Sealed + Copy {
+    /// The size of this integer in bytes.
+    const BYTES: usize;
+
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl Int for $ty {
+            const BYTES: usize = core::mem::size_of::<$ty>();
+
+            fn to_u128(self) -> u128 {
+                self.into()
+            }
+
+            fn from_u128(value: u128) -> Self {
+                let mut bytes = [0; Self::BYTES];
+                bytes.copy_from_slice(&value.to_le_bytes()[..Self::BYTES]);
+                Self::from_le_bytes(bytes)
+            }
+        }
+    )*};
+}
+
+impl_int!(u8, u16, u32, u64, u128);
+
+/// Write the significant bytes of `value` into `output` in the given byte order, returning how
+/// many were written.
+#[cfg(feature = "alloc")]
+pub(crate) fn to_bytes<T: Int>(value: T, endianness: Endianness, output: &mut [u8; 16]) -> usize {
+    let value = value.to_u128();
+    let len = 16 - (value.leading_zeros() / 8) as usize;
+    let bytes = match endianness {
+        Endianness::Big => value.to_be_bytes(),
+        Endianness::Little => value.to_le_bytes(),
+    };
+    let significant = match endianness {
+        Endianness::Big => &bytes[16 - len..],
+        Endianness::Little => &bytes[..len],
+    };
+    output[..len].copy_from_slice(significant);
+    len
+}
+
+/// Read `bytes` as an integer in the given byte order, the caller having checked that it fits.
+pub(crate) fn from_bytes<T: Int>(bytes: &[u8], endianness: Endianness) -> T {
+    let fold = |acc: u128, &byte: &u8| (acc << 8) | u128::from(byte);
+    T::from_u128(match endianness {
+        Endianness::Big => bytes.iter().fold(0, fold),
+        Endianness::Little => bytes.iter().rev().fold(0, fold),
+    })
+}
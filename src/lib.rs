@@ -33,15 +33,26 @@
 //!
 //! # Features
 //!
-//!  Feature | Activation         | Effect
-//! ---------|--------------------|--------
-//!  `std`   | **on**-by-default  | Implement [`Error`](std::error::Error) for error types
-//!  `alloc` | implied by `std`   | Support encoding/decoding to [`Vec`](alloc::vec::Vec) and [`String`](alloc::string::String) as appropriate
-//!  `check` | **off**-by-default | Integrated support for [Base58Check][]
-//!  `cb58`  | **off**-by-default | Integrated support for [CB58][]
+//!  Feature            | Activation         | Effect
+//! --------------------|--------------------|--------
+//!  `std`              | **on**-by-default  | Implement [`Error`](std::error::Error) for error types
+//!  `alloc`            | implied by `std`   | Support encoding/decoding to [`Vec`](alloc::vec::Vec) and [`String`](alloc::string::String) as appropriate
+//!  `check`            | **off**-by-default | Integrated support for [Base58Check][]
+//!  `cb58`             | **off**-by-default | Integrated support for [CB58][]
+//!  `check-core`       | implied by `check` | [Base58Check][] support without the `sha2` dependency, see [`Sha256`]
+//!  `cb58-core`        | implied by `cb58`  | [CB58][] support without the `sha2` dependency, see [`Sha256`]
+//!  `percent-encoding` | **off**-by-default | Support [percent-decoding][] URL-encoded input before decoding
+//!  `test-vectors`     | **off**-by-default | Expose the crate's [test vectors](test_vectors) for use in other crates' tests
+//!  `serde`            | **off**-by-default | [Serde][] helpers for fixed-size base58 fields, see [`serde_fixed`]
+//!  `rayon`            | **off**-by-default | Verify batches in parallel in [`verify_check_batch`]
+//!  `uuid`             | **off**-by-default | Encode and decode [`uuid::Uuid`](::uuid::Uuid) with the [`uuid`](mod@uuid) module
+//!  `bumpalo`          | **off**-by-default | Decode into arena-allocated vectors from [`bumpalo`][bumpalo]
 //!
 //! [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
 //! [CB58]: https://support.avax.network/en/articles/4587395-what-is-cb58
+//! [percent-decoding]: https://url.spec.whatwg.org/#percent-decode
+//! [Serde]: https://serde.rs
+//! [bumpalo]: https://docs.rs/bumpalo
 //!
 //! # Examples
 //!
@@ -90,20 +101,258 @@ pub use alphabet::Alphabet;
 pub mod decode;
 pub mod encode;
 
-#[cfg(any(feature = "check", feature = "cb58"))]
+#[cfg(feature = "alloc")]
+mod hex;
+
+pub mod int;
+
+#[cfg(feature = "serde")]
+pub mod serde_fixed;
+
+#[cfg(feature = "alloc")]
+pub mod solana;
+
+#[cfg(feature = "uuid")]
+pub mod uuid;
+
+/// Test vectors used by this crate's own tests, for other implementations and wrappers to check
+/// their conformance against.
+///
+/// ```rust
+/// for &(decoded, encoded) in bs58::test_vectors::TEST_CASES {
+///     assert_eq!(encoded, bs58::encode(decoded).into_string());
+/// }
+/// ```
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
 const CHECKSUM_LEN: usize = 4;
 
+/// A SHA-256 implementation used to calculate checksums, for use with
+/// [`EncodeBuilder::with_sha256`](encode::EncodeBuilder::with_sha256) and
+/// [`DecodeBuilder::with_sha256`](decode::DecodeBuilder::with_sha256).
+///
+/// By default the [`sha2`](https://docs.rs/sha2) crate is used. Projects that already have
+/// another implementation, such as a hardware accelerated one, can enable the `check-core` or
+/// `cb58-core` features instead of `check` or `cb58` to avoid depending on `sha2`, and must
+/// then provide it to every checked encode and decode. Enabling a checked mode without one fails
+/// to compile, see [`DefaultSha256`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")]
+/// # fn main() -> Result<(), bs58::decode::Error> {
+/// use sha2::Digest;
+///
+/// struct MySha256;
+///
+/// impl bs58::Sha256 for MySha256 {
+///     fn digest(data: &[&[u8]]) -> [u8; 32] {
+///         let mut hasher = sha2::Sha256::new();
+///         for part in data {
+///             hasher.update(part);
+///         }
+///         hasher.finalize().into()
+///     }
+/// }
+///
+/// let input = [0x2d, 0x31];
+/// let encoded = bs58::encode(input).with_check().with_sha256::<MySha256>().into_string();
+/// assert_eq!("PWEu9GGN", encoded);
+/// assert_eq!(
+///     input.to_vec(),
+///     bs58::decode(encoded).with_check(None).with_sha256::<MySha256>().into_vec()?);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "check"))]
+/// # fn main() {}
+/// ```
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+pub trait Sha256 {
+    /// Calculate the SHA-256 digest of the concatenation of `data`.
+    fn digest(data: &[&[u8]]) -> [u8; 32];
+
+    /// Calculate the SHA-256 digest of the data passed to `update` by `produce`, so that it can be
+    /// hashed while it is being produced rather than in a second pass once complete. When
+    /// decoding Base58Check this hashes each block of the payload as soon as it is written.
+    ///
+    /// Implementing this is optional, the default returns `None` without calling `produce`, in
+    /// which case the data is hashed afterwards with [`Self::digest`]. Implementations returning
+    /// a digest must call `produce` exactly once. [`DefaultSha256`] doesn't implement it, as with
+    /// the `sha2` crate decoding dominates and the second pass makes no measurable difference
+    /// (see the `check` benches), but it may help hashers with a high cost per pass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "check")]
+    /// # fn main() -> Result<(), bs58::decode::Error> {
+    /// use sha2::Digest;
+    ///
+    /// struct Incremental;
+    ///
+    /// impl bs58::Sha256 for Incremental {
+    ///     fn digest(data: &[&[u8]]) -> [u8; 32] {
+    ///         Self::digest_incremental(&mut |update| data.iter().for_each(|part| update(part)))
+    ///             .unwrap()
+    ///     }
+    ///
+    ///     fn digest_incremental(
+    ///         produce: &mut dyn FnMut(&mut bs58::Sha256Update<'_>),
+    ///     ) -> Option<[u8; 32]> {
+    ///         let mut hasher = sha2::Sha256::new();
+    ///         produce(&mut |part| hasher.update(part));
+    ///         Some(hasher.finalize().into())
+    ///     }
+    /// }
+    ///
+    /// let encoded = bs58::encode([0x2d, 0x31]).with_check().into_string();
+    /// assert_eq!(
+    ///     vec![0x2d, 0x31],
+    ///     bs58::decode(encoded).with_check(None).with_sha256::<Incremental>().into_vec()?);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "check"))]
+    /// # fn main() {}
+    /// ```
+    fn digest_incremental(produce: &mut dyn FnMut(&mut Sha256Update<'_>)) -> Option<[u8; 32]> {
+        let _ = produce;
+        None
+    }
+}
+
+/// Adds the next part of the data to a digest being calculated by
+/// [`Sha256::digest_incremental`].
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+pub type Sha256Update<'a> = dyn FnMut(&[u8]) + 'a;
+
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+type Sha256Fn = fn(&[&[u8]]) -> [u8; 32];
+
+#[cfg(feature = "check-core")]
+type Sha256IncrementalFn = fn(&mut dyn FnMut(&mut Sha256Update<'_>)) -> Option<[u8; 32]>;
+
+/// The SHA-256 implementation used for checksums by [`EncodeBuilder`](encode::EncodeBuilder)
+/// and [`DecodeBuilder`](decode::DecodeBuilder) until another is chosen with `with_sha256`.
+///
+/// This implements `Sha256` using the [`sha2`](https://docs.rs/sha2) crate when the `sha2`
+/// dependency is enabled, as it is by the `check` and `cb58` features. Otherwise it implements
+/// nothing, and the builder methods enabling a checked mode are only available after calling
+/// `with_sha256`:
+///
+/// ```rust
+/// # #[cfg(feature = "check-core")]
+/// # fn main() {
+/// struct Zero;
+///
+/// impl bs58::Sha256 for Zero {
+///     fn digest(_: &[&[u8]]) -> [u8; 32] {
+///         [0; 32]
+///     }
+/// }
+///
+/// assert_eq!("7YXq9H", bs58::encode([1]).with_sha256::<Zero>().with_check().into_string());
+/// # }
+/// # #[cfg(not(feature = "check-core"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultSha256;
+
+#[cfg(feature = "sha2")]
+impl Sha256 for DefaultSha256 {
+    fn digest(data: &[&[u8]]) -> [u8; 32] {
+        use sha2::Digest;
+
+        let mut hasher = sha2::Sha256::new();
+        for part in data {
+            hasher.update(part);
+        }
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+fn default_sha256(data: &[&[u8]]) -> [u8; 32] {
+    #[cfg(feature = "sha2")]
+    {
+        DefaultSha256::digest(data)
+    }
+    // Checked modes require `H: Sha256` on the builders, which `DefaultSha256` doesn't implement
+    // without `sha2`, so this is never called in that case
+    #[cfg(not(feature = "sha2"))]
+    {
+        let _ = data;
+        unreachable!()
+    }
+}
+
+#[cfg(feature = "check-core")]
+fn default_sha256_incremental(_: &mut dyn FnMut(&mut Sha256Update<'_>)) -> Option<[u8; 32]> {
+    // `DefaultSha256` only hashes in one shot, see `Sha256::digest_incremental`
+    None
+}
+
+/// A user provided checksum function, which writes the checksum of the payload in its first
+/// argument into the second.
+type CheckFn<'a> = &'a dyn Fn(&[u8], &mut [u8]);
+
+/// The maximum length of a checksum calculated by a [`CheckFn`].
+const MAX_CHECK_FN_LEN: usize = 64;
+
 /// Possible check variants.
-enum Check {
+enum Check<'a> {
     Disabled,
-    #[cfg(feature = "check")]
-    Enabled(Option<u8>),
-    #[cfg(feature = "cb58")]
-    CB58(Option<u8>),
+    #[cfg(feature = "check-core")]
+    Enabled(Version<'a>),
+    #[cfg(feature = "cb58-core")]
+    CB58(Version<'a>),
+    #[cfg(feature = "check-core")]
+    Delimited(u8),
+    Custom {
+        len: usize,
+        f: CheckFn<'a>,
+    },
+}
+
+/// Version bytes included in a checked encoding, may be empty.
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+#[derive(Clone, Copy)]
+#[allow(variant_size_differences)] // the slice is only a pointer and length, boxing can't help
+enum Version<'a> {
+    Byte(u8),
+    Bytes(&'a [u8]),
+    #[cfg(feature = "check-core")]
+    Pair([u8; 2]),
+}
+
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+impl Version<'_> {
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            Version::Byte(byte) => core::slice::from_ref(byte),
+            Version::Bytes(bytes) => bytes,
+            #[cfg(feature = "check-core")]
+            Version::Pair(bytes) => bytes,
+        }
+    }
+}
+
+#[cfg(any(feature = "check-core", feature = "cb58-core"))]
+impl From<Option<u8>> for Version<'_> {
+    fn from(version: Option<u8>) -> Self {
+        version.map_or(Version::Bytes(&[]), Version::Byte)
+    }
 }
 
 /// Setup decoder for the given string using the [default alphabet][Alphabet::DEFAULT].
 ///
+/// Decoding is case-sensitive, a character is only accepted in the exact case it appears in the
+/// alphabet. [`Error::with_alphabet`](decode::Error::with_alphabet) can be used to get a hint when
+/// an invalid character is a case variant of a valid one.
+///
 /// # Examples
 ///
 /// ## Basic example
@@ -171,6 +420,130 @@ pub const fn decode<I: AsRef<[u8]>>(input: I) -> decode::DecodeBuilder<'static,
     decode::DecodeBuilder::from_input(input)
 }
 
+/// Convert a string encoded with one alphabet into the encoding of the same data with another, for
+/// migrating data between alphabets such as Bitcoin's and Ripple's orderings.
+///
+/// Different alphabets order the digits differently, so this still decodes and re-encodes the
+/// data, but does so using a single scratch buffer shared between both steps.
+///
+/// # Examples
+///
+/// ```rust
+/// use bs58::Alphabet;
+///
+/// assert_eq!("6erroAoird", bs58::transcode("he11owor1d", Alphabet::BITCOIN, Alphabet::RIPPLE)?);
+/// assert_eq!("he11owor1d", bs58::transcode("6erroAoird", Alphabet::RIPPLE, Alphabet::BITCOIN)?);
+///
+/// assert_eq!(
+///     bs58::decode::Error::InvalidCharacter { character: 'l', index: 2 },
+///     bs58::transcode("hello", Alphabet::BITCOIN, Alphabet::RIPPLE).unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transcode(
+    input: &str,
+    from: &Alphabet,
+    to: &Alphabet,
+) -> decode::Result<alloc::string::String> {
+    let decoded_len = decode::max_decoded_len(input.as_bytes(), from);
+    let encoded_len =
+        encode::max_encoded_len(decoded_len).map_err(|_| decode::Error::BufferTooSmall)?;
+    let mut scratch = alloc::vec![0; decoded_len + encoded_len];
+    let (decoded, encoded) = scratch.split_at_mut(decoded_len);
+    let len = decode(input).with_alphabet(from).onto(&mut *decoded)?;
+    let len = encode(&decoded[..len])
+        .with_alphabet(to)
+        .onto(encoded)
+        .expect("max_encoded_len is always large enough");
+    scratch.drain(..decoded_len);
+    scratch.truncate(len);
+    // The alphabet is guaranteed ASCII
+    Ok(alloc::string::String::from_utf8(scratch).unwrap())
+}
+
+/// Decode the given string with the first of the built-in alphabets it is valid for, returning
+/// the alphabet that was used along with the decoded bytes.
+///
+/// This is shorthand for
+/// [`bs58::decode(input).into_vec_detecting_alphabet()`](decode::DecodeBuilder::into_vec_detecting_alphabet),
+/// see that for the order the alphabets are tried in. As most of the built-in alphabets contain
+/// the same characters this can't distinguish them without a checksum, use the builder with
+/// [`with_check`](decode::DecodeBuilder::with_check) for checked input such as addresses.
+///
+/// # Examples
+///
+/// ```rust
+/// let (decoded, alphabet) = bs58::decode_auto("he11owor1d")?;
+/// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decoded);
+/// assert_eq!(bs58::Alphabet::BITCOIN, alphabet);
+///
+/// assert_eq!(
+///     bs58::decode::Error::InvalidCharacter { character: '0', index: 0 },
+///     bs58::decode_auto("0x1234").unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_auto<I: AsRef<[u8]>>(
+    input: I,
+) -> decode::Result<(alloc::vec::Vec<u8>, &'static Alphabet)> {
+    decode(input).into_vec_detecting_alphabet()
+}
+
+/// Setup decoder for the given characters, for parsers working with `&[char]` rather than strings.
+///
+/// Any non-ASCII character is reported as
+/// [`Error::NonAsciiCharacter`](decode::Error::NonAsciiCharacter), and errors index into the slice
+/// of characters.
+///
+/// # Examples
+///
+/// ```rust
+/// let chars: Vec<char> = "he11owor1d".chars().collect();
+/// assert_eq!(
+///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     bs58::decode_chars(&chars).into_vec()?);
+///
+/// let chars: Vec<char> = "he11o🇳🇿".chars().collect();
+/// assert_eq!(
+///     bs58::decode::Error::NonAsciiCharacter { index: 5 },
+///     bs58::decode_chars(&chars).into_vec().unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_chars(input: &[char]) -> decode::DecodeBuilder<'static, alloc::vec::Vec<u8>> {
+    // Non-ASCII characters are replaced by a single non-ASCII byte, keeping indexes the same
+    decode(
+        input
+            .iter()
+            .map(|c| if c.is_ascii() { *c as u8 } else { 0x80 })
+            .collect::<alloc::vec::Vec<u8>>(),
+    )
+}
+
+/// Setup decoder for the given OS string or path, e.g. for data encoded into filenames.
+///
+/// Any non-ASCII content in the string is reported as
+/// [`Error::NonAsciiCharacter`](decode::Error::NonAsciiCharacter), with the index into its
+/// [platform specific encoding](std::ffi::OsStr::as_encoded_bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// let path = Path::new("he11owor1d");
+/// assert_eq!(
+///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     bs58::decode_os(path.file_name().unwrap()).into_vec()?);
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_os<S: AsRef<std::ffi::OsStr> + ?Sized>(
+    input: &S,
+) -> decode::DecodeBuilder<'static, &[u8]> {
+    decode(input.as_ref().as_encoded_bytes())
+}
+
 /// Setup encoder for the given bytes using the [default alphabet][Alphabet::DEFAULT].
 ///
 /// # Examples
@@ -216,6 +589,681 @@ pub const fn decode<I: AsRef<[u8]>>(input: I) -> decode::DecodeBuilder<'static,
 ///     bs58::encode::Error::BufferTooSmall,
 ///     bs58::encode(input).onto(&mut output[..]).unwrap_err());
 /// ```
-pub fn encode<I: AsRef<[u8]>>(input: I) -> encode::EncodeBuilder<'static, I> {
+pub const fn encode<I: AsRef<[u8]>>(input: I) -> encode::EncodeBuilder<'static, I> {
     encode::EncodeBuilder::from_input(input)
 }
+
+/// Parse a hex string and encode the bytes it represents, for tools with hex input.
+///
+/// Both lowercase and uppercase hex digits are accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!("he11owor1d", bs58::encode_hex("04305e2b2473F058")?);
+///
+/// assert_eq!(
+///     bs58::encode::Error::InvalidHex { index: 2 },
+///     bs58::encode_hex("04x0").unwrap_err());
+/// assert_eq!(
+///     bs58::encode::Error::InvalidHex { index: 3 },
+///     bs58::encode_hex("043").unwrap_err());
+/// # Ok::<(), bs58::encode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_hex(hex: &str) -> encode::Result<alloc::string::String> {
+    let bytes = hex::decode(hex.as_bytes())?;
+    Ok(encode(bytes).into_string())
+}
+
+/// Encode an integer as its significant bytes in the given byte order, for compact numeric IDs.
+///
+/// **Use [`Endianness::Big`](int::Endianness::Big) unless you know otherwise**; it is the
+/// [default](int::Endianness::default), matches the Bitcoin convention and is what
+/// [`DecodeBuilder::into_u64`](decode::DecodeBuilder::into_u64) expects. High-order zero bytes
+/// are dropped, so zero encodes to an empty string, and the result can be decoded with
+/// [`DecodeBuilder::into_int`](decode::DecodeBuilder::into_int) using the same byte order.
+///
+/// # Examples
+///
+/// ```rust
+/// use bs58::int::Endianness;
+///
+/// assert_eq!("he11owor1d", bs58::encode_int(0x04305e2b2473f058_u64, Endianness::Big));
+/// assert_eq!("z", bs58::encode_int(57_u8, Endianness::Big));
+/// assert_eq!("", bs58::encode_int(0_u32, Endianness::default()));
+///
+/// assert_eq!("5R", bs58::encode_int(0x0100_u16, Endianness::Big));
+/// assert_eq!("12", bs58::encode_int(0x0100_u16, Endianness::Little));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_int<T: int::Int>(n: T, endianness: int::Endianness) -> alloc::string::String {
+    let mut bytes = [0; 16];
+    let len = int::to_bytes(n, endianness, &mut bytes);
+    encode(&bytes[..len]).into_string()
+}
+
+/// The length of a UUID encoded by [`encode_uuid`], enough for any 128-bit value.
+pub const UUID_ENCODED_LEN: usize = 22;
+
+/// Encode a UUID as a fixed-width [`UUID_ENCODED_LEN`] character string, for URL-friendly IDs.
+///
+/// The UUID is treated as a big-endian 128-bit number and left-padded with `1` (zero in the
+/// [`BITCOIN`](Alphabet::BITCOIN) alphabet) to the full width. This differs from
+/// [`bs58::encode`](encode()) of its 16 bytes, which would produce one `1` per leading zero byte
+/// and so a variable length.
+///
+/// With the `uuid` feature, the `bs58::uuid` module provides the same for `uuid::Uuid`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     "KCtqn2CDK64hXvWfYwhRnP",
+///     bs58::encode_uuid(0x936da01f_9abd_4d9d_80c7_02af85c822a8));
+/// assert_eq!("1111111111111111111112", bs58::encode_uuid(1));
+/// assert_eq!("YcVfxkQb6JRzqk5kF2tNLv", bs58::encode_uuid(u128::MAX));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_uuid(uuid: u128) -> alloc::string::String {
+    let digits = encode_int(uuid, int::Endianness::Big);
+    let mut encoded = alloc::string::String::with_capacity(UUID_ENCODED_LEN);
+    encoded.extend(core::iter::repeat_n('1', UUID_ENCODED_LEN - digits.len()));
+    encoded.push_str(&digits);
+    encoded
+}
+
+/// Decode a UUID encoded by [`encode_uuid`].
+///
+/// See the documentation for [`bs58::decode`](crate::decode()) for an explanation of the errors
+/// that may occur, along with
+/// [`Error::InvalidUuidLength`](decode::Error::InvalidUuidLength) if the input is not exactly
+/// [`UUID_ENCODED_LEN`] characters and [`Error::IntegerOverflow`](decode::Error::IntegerOverflow)
+/// if its value doesn't fit in 128 bits.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     0x936da01f_9abd_4d9d_80c7_02af85c822a8,
+///     bs58::decode_uuid("KCtqn2CDK64hXvWfYwhRnP")?);
+/// assert_eq!(1, bs58::decode_uuid("1111111111111111111112")?);
+///
+/// assert_eq!(
+///     bs58::decode::Error::InvalidUuidLength { len: 1 },
+///     bs58::decode_uuid("2").unwrap_err());
+/// assert_eq!(
+///     bs58::decode::Error::IntegerOverflow,
+///     bs58::decode_uuid("YcVfxkQb6JRzqk5kF2tNLw").unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+pub fn decode_uuid<I: AsRef<[u8]>>(input: I) -> decode::Result<u128> {
+    let len = input.as_ref().len();
+    if len != UUID_ENCODED_LEN {
+        return Err(decode::Error::InvalidUuidLength { len });
+    }
+    // Leading `1`s are padding, not zero bytes
+    decode(input).padded().into_int(int::Endianness::Big)
+}
+
+/// Encode several byte slices as if they were concatenated, using the
+/// [default alphabet](Alphabet::DEFAULT), without allocating the concatenation first.
+///
+/// This is the same as `bs58::encode(parts.concat()).into_string()`, for encoding values such as
+/// `version || payload || suffix` that are stored separately.
+///
+/// # Examples
+///
+/// ```rust
+/// let version = [0x04, 0x30];
+/// let payload = [0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+/// assert_eq!("he11owor1d", bs58::encode_concat(&[&version, &payload]));
+///
+/// // Leading zero bytes are preserved across parts
+/// assert_eq!("112", bs58::encode_concat(&[&[0], &[], &[0, 1]]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_concat(parts: &[&[u8]]) -> alloc::string::String {
+    use encode::EncodeTarget;
+
+    let len = parts.iter().map(|part| part.len()).sum();
+    let max_len = encode::max_encoded_len(len).expect("input is too large to encode");
+    let mut output = alloc::string::String::new();
+    output
+        .encode_with(max_len, |output| {
+            encode::encode_into(parts.iter().copied().flatten(), output, Alphabet::DEFAULT)
+        })
+        .expect("max_encoded_len is always large enough");
+    output
+}
+
+/// Encode a slice of integers by packing each one as 4 big-endian bytes, for compactly
+/// representing numeric arrays.
+///
+/// Leading zero bytes are preserved by base58, so the number of integers, including any leading
+/// zeros, is recovered by [`decode_u32_slice`].
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!("1111111E64fHY", bs58::encode_u32_slice(&[0, 1, 0xFFFF_FFFF]));
+/// assert_eq!(vec![0, 1, 0xFFFF_FFFF], bs58::decode_u32_slice("1111111E64fHY")?);
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_u32_slice(input: &[u32]) -> alloc::string::String {
+    let bytes: alloc::vec::Vec<u8> = input.iter().flat_map(|n| n.to_be_bytes()).collect();
+    encode(bytes).into_string()
+}
+
+/// Decode a string encoded by [`encode_u32_slice`] back into its integers, reading each one from
+/// 4 big-endian bytes.
+///
+/// See the documentation for [`bs58::decode`](crate::decode()) for an explanation of the errors
+/// that may occur, along with [`Error::UnalignedLength`](decode::Error::UnalignedLength) if the
+/// decoded data is not a multiple of 4 bytes long.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(vec![0x04305e2b, 0x2473f058], bs58::decode_u32_slice("he11owor1d")?);
+///
+/// assert_eq!(
+///     bs58::decode::Error::UnalignedLength { len: 5, alignment: 4 },
+///     bs58::decode_u32_slice("EUYUqQf").unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_u32_slice<I: AsRef<[u8]>>(input: I) -> decode::Result<alloc::vec::Vec<u32>> {
+    let bytes = decode(input).into_vec()?;
+    let (chunks, remainder) = bytes.as_chunks::<4>();
+    if !remainder.is_empty() {
+        return Err(decode::Error::UnalignedLength {
+            len: bytes.len(),
+            alignment: 4,
+        });
+    }
+    Ok(chunks
+        .iter()
+        .map(|chunk| u32::from_be_bytes(*chunk))
+        .collect())
+}
+
+/// The size of array needed to encode an input of `len` bytes, for use as the output length of
+/// [`into_array_const`](encode::EncodeBuilder::into_array_const) with fixed-size inputs.
+///
+/// This is an upper bound; encoded strings may be shorter, leaving trailing zero bytes in the
+/// array. It does not account for version or checksum bytes.
+///
+/// # Panics
+///
+/// If the result would overflow a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// const KEY: [u8; 8] = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+/// const ENCODED: [u8; bs58::encoded_array_len(KEY.len())] =
+///     bs58::encode(KEY.as_slice()).into_array_const_unwrap();
+///
+/// assert_eq!(12, ENCODED.len());
+/// assert_eq!(b"he11owor1d\0\0", &ENCODED);
+/// ```
+pub const fn encoded_array_len(len: usize) -> usize {
+    match encode::max_encoded_len(len) {
+        Ok(max_len) => max_len,
+        Err(err) => err.unwrap_const(),
+    }
+}
+
+/// The shortest and longest possible encodings of `byte_len` bytes in characters, for checking
+/// that an input's length is plausible for an expected payload size before decoding it.
+///
+/// The shortest is when every byte is zero, as each leading zero byte becomes one character and
+/// any other value takes at least as many characters as bytes. The longest is when every byte is
+/// `0xFF`, which is exact unlike the array length from [`encoded_array_len`]. Neither accounts for
+/// version or checksum bytes.
+///
+/// # Panics
+///
+/// If the result would overflow a `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!((32, 44), bs58::char_len_range(32));
+/// assert_eq!((0, 0), bs58::char_len_range(0));
+///
+/// const RANGE: (usize, usize) = bs58::char_len_range(8);
+/// assert!((RANGE.0..=RANGE.1).contains(&"he11owor1d".len()));
+/// ```
+pub const fn char_len_range(byte_len: usize) -> (usize, usize) {
+    // The fractional part of log(256) / log(58), the number of base58 digits per byte, as a
+    // 128-bit fixed point number. This gives the exact number of digits for any 64-bit length.
+    const DIGITS_PER_BYTE_FRACTION: u128 = 0x5d9bc73a_c2288342_ba2898f9_43343e0a;
+
+    if byte_len == 0 {
+        return (0, 0);
+    }
+
+    // 256^n - 1 has floor(n * log(256) / log(58)) + 1 digits, as n * log(256) / log(58) is never
+    // an integer. The product is split into halves of the fraction so it fits in a u128.
+    let n = byte_len as u128;
+    let high = n * (DIGITS_PER_BYTE_FRACTION >> 64);
+    let low = (n * (DIGITS_PER_BYTE_FRACTION as u64 as u128)) >> 64;
+    let max = n + ((high + low) >> 64) + 1;
+    assert!(max <= usize::MAX as u128, "encoded length overflowed usize");
+    (byte_len, max as usize)
+}
+
+/// Compare two Base58 encoded strings by their decoded value, without decoding them.
+///
+/// Lexicographic ordering of encoded strings doesn't match the ordering of the numbers they
+/// represent, this compares the numeric values instead. Leading zero bytes don't change the
+/// value, so strings which only differ in leading zeros are ordered by their decoded length,
+/// making this consistent with equality of the decoded bytes.
+///
+/// See the documentation for [`bs58::decode`](crate::decode()) for an explanation of the errors
+/// that may occur, if both inputs are invalid the error is for `a`.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// let alpha = bs58::Alphabet::DEFAULT;
+/// // "z" < "21" when compared as strings
+/// assert_eq!(Ordering::Greater, bs58::cmp("21", "z", alpha)?);
+/// assert_eq!(Ordering::Greater, bs58::cmp("z", "12", alpha)?);
+/// assert_eq!(Ordering::Equal, bs58::cmp("12", "12", alpha)?);
+/// assert_eq!(Ordering::Greater, bs58::cmp("112", "12", alpha)?);
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+pub fn cmp(
+    a: impl AsRef<[u8]>,
+    b: impl AsRef<[u8]>,
+    alpha: &Alphabet,
+) -> decode::Result<core::cmp::Ordering> {
+    decode::cmp(a.as_ref(), b.as_ref(), alpha)
+}
+
+/// Check whether `encoded` decodes to exactly `expected`, returning `false` on any decode error
+/// or mismatch, for validating input against a known key in hot paths.
+///
+/// Inputs that can't match because of their length or leading zeros are rejected before
+/// decoding. Expected values of up to 128 bytes are compared by decoding onto the stack without
+/// allocating, longer ones are decoded into a vector.
+///
+/// # Examples
+///
+/// ```rust
+/// let alpha = bs58::Alphabet::DEFAULT;
+/// let key = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+/// assert!(bs58::matches("he11owor1d", &key, alpha));
+/// assert!(!bs58::matches("he11owor1e", &key, alpha));
+/// assert!(!bs58::matches("1he11owor1d", &key, alpha));
+/// assert!(!bs58::matches("he11o world", &key, alpha));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn matches(encoded: impl AsRef<[u8]>, expected: &[u8], alpha: &Alphabet) -> bool {
+    let encoded = encoded.as_ref();
+
+    // Each leading zero byte is exactly one leading zero character
+    let zeros = encoded.iter().take_while(|c| alpha.is_zero(**c)).count();
+    let expected_zeros = expected.iter().take_while(|b| **b == 0).count();
+    if zeros != expected_zeros || decode::max_decoded_len(encoded, alpha) < expected.len() {
+        return false;
+    }
+
+    let mut scratch = [0; 128];
+    if let Some(scratch) = scratch.get_mut(..expected.len()) {
+        // Anything longer than `expected` fails with `BufferTooSmall`
+        return decode(encoded)
+            .with_alphabet(alpha)
+            .onto(&mut *scratch)
+            .is_ok_and(|len| scratch[..len] == *expected);
+    }
+
+    decode(encoded)
+        .with_alphabet(alpha)
+        .into_vec()
+        .is_ok_and(|decoded| decoded == expected)
+}
+
+/// Split a checked encoding using the [default alphabet][Alphabet::DEFAULT] into its version,
+/// payload and checksum, reporting whether the checksum is valid rather than failing on a
+/// mismatch.
+///
+/// This is intended for diagnosing malformed addresses and keys, where seeing every part of the
+/// input is more useful than the single error from
+/// [`DecodeBuilder::with_check`](decode::DecodeBuilder::with_check).
+///
+/// See the documentation for [`bs58::decode`](crate::decode()) for an explanation of the errors
+/// that may occur, along with [`Error::NoChecksum`](decode::Error::NoChecksum) if the input is too
+/// short to contain a checksum.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")]
+/// # fn main() -> Result<(), bs58::decode::Error> {
+/// use bs58::decode::{CheckParts, CheckScheme};
+///
+/// assert_eq!(
+///     CheckParts {
+///         version: Some(0x2d),
+///         payload: vec![0x31],
+///         checksum: [0x11, 0x46, 0x76, 0x77],
+///         checksum_valid: true,
+///     },
+///     bs58::inspect_check("PWEu9GGN", CheckScheme::Check)?);
+///
+/// assert_eq!(
+///     CheckParts {
+///         version: Some(0x2d),
+///         payload: vec![0x31],
+///         checksum: [0x11, 0x46, 0x76, 0x78],
+///         checksum_valid: false,
+///     },
+///     bs58::inspect_check("PWEu9GGP", CheckScheme::Check)?);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "check"))]
+/// # fn main() {}
+/// ```
+#[cfg(all(
+    any(feature = "check-core", feature = "cb58-core"),
+    feature = "sha2",
+    feature = "alloc"
+))]
+pub fn inspect_check(
+    input: impl AsRef<[u8]>,
+    scheme: decode::CheckScheme,
+) -> decode::Result<decode::CheckParts> {
+    inspect_check_with_sha256::<DefaultSha256>(input, scheme)
+}
+
+/// Split a checked encoding into its parts as [`inspect_check`](crate::inspect_check()) does,
+/// using the given SHA-256 implementation to calculate the checksum.
+///
+/// See [`Sha256`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")]
+/// # fn main() -> Result<(), bs58::decode::Error> {
+/// use bs58::decode::CheckScheme;
+///
+/// struct Zero;
+///
+/// impl bs58::Sha256 for Zero {
+///     fn digest(_: &[&[u8]]) -> [u8; 32] {
+///         [0; 32]
+///     }
+/// }
+///
+/// let encoded = bs58::encode([0x2d, 0x31]).with_sha256::<Zero>().with_check().into_string();
+/// let parts = bs58::inspect_check_with_sha256::<Zero>(&encoded, CheckScheme::Check)?;
+/// assert!(parts.checksum_valid);
+/// assert!(!bs58::inspect_check(&encoded, CheckScheme::Check)?.checksum_valid);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "check"))]
+/// # fn main() {}
+/// ```
+#[cfg(all(any(feature = "check-core", feature = "cb58-core"), feature = "alloc"))]
+pub fn inspect_check_with_sha256<H: Sha256>(
+    input: impl AsRef<[u8]>,
+    scheme: decode::CheckScheme,
+) -> decode::Result<decode::CheckParts> {
+    decode::inspect_check(input.as_ref(), scheme, H::digest)
+}
+
+/// Replace the version byte of a [Base58Check][] string, such as when migrating an address
+/// between networks, verifying the original checksum and calculating a new one.
+///
+/// Returns [`decode::Error::NoChecksum`] if the decoded data is too short to contain a version
+/// byte along with the checksum.
+///
+/// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")]
+/// # fn main() -> Result<(), bs58::decode::Error> {
+/// // A mainnet P2PKH address (version 0x00) as a testnet address (version 0x6f)
+/// assert_eq!(
+///     "mrS8eVKXguwufwvsVe9GtgGb7fif9UQeAu",
+///     bs58::rewrite_check_version("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", 0x6f)?);
+///
+/// assert!(matches!(
+///     bs58::rewrite_check_version("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3", 0x6f),
+///     Err(bs58::decode::Error::InvalidChecksum { .. })));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "check"))]
+/// # fn main() {}
+/// ```
+#[cfg(all(feature = "check-core", feature = "sha2", feature = "alloc"))]
+pub fn rewrite_check_version(
+    input: impl AsRef<[u8]>,
+    version: u8,
+) -> decode::Result<alloc::string::String> {
+    rewrite_check_version_with_sha256::<DefaultSha256>(input, version)
+}
+
+/// Replace the version byte of a [Base58Check][] string as
+/// [`rewrite_check_version`](crate::rewrite_check_version()) does, using the given SHA-256
+/// implementation to verify and calculate the checksums.
+///
+/// See [`Sha256`] for more details.
+///
+/// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")]
+/// # fn main() -> Result<(), bs58::decode::Error> {
+/// struct Zero;
+///
+/// impl bs58::Sha256 for Zero {
+///     fn digest(_: &[&[u8]]) -> [u8; 32] {
+///         [0; 32]
+///     }
+/// }
+///
+/// let encoded = bs58::encode([0x00, 0x31]).with_sha256::<Zero>().with_check().into_string();
+/// let rewritten = bs58::rewrite_check_version_with_sha256::<Zero>(&encoded, 0x6f)?;
+/// assert_eq!(
+///     vec![0x6f, 0x31],
+///     bs58::decode(rewritten).with_sha256::<Zero>().with_check(None).into_vec()?);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "check"))]
+/// # fn main() {}
+/// ```
+#[cfg(all(feature = "check-core", feature = "alloc"))]
+pub fn rewrite_check_version_with_sha256<H: Sha256>(
+    input: impl AsRef<[u8]>,
+    version: u8,
+) -> decode::Result<alloc::string::String> {
+    let mut decoded = decode(input)
+        .with_sha256::<H>()
+        .with_check(None)
+        .into_vec()?;
+    *decoded.first_mut().ok_or(decode::Error::NoChecksum)? = version;
+    Ok(encode(decoded)
+        .with_sha256::<H>()
+        .with_check()
+        .into_string())
+}
+
+/// Decode and verify a batch of [Base58Check][] strings, such as addresses, returning the result
+/// for each input in the same order.
+///
+/// Each input is handled as if by
+/// [`bs58::decode(input).with_check(expected_ver).into_vec()`](decode::DecodeBuilder::with_check),
+/// so the decoded data includes the version byte. When the `rayon` feature is enabled the inputs
+/// are verified in parallel.
+///
+/// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+///
+/// # Examples
+///
+/// ```rust
+/// let results = bs58::verify_check_batch(
+///     &["1AGNa15ZQXAZUgFiqJ2i7Z2DPU2J6hW62i", "3QJmV3qfvL9SuYo34YihAf3sRCW3qSinyC"],
+///     Some(0x00),
+/// );
+/// assert_eq!(2, results.len());
+/// assert_eq!(0x00, results[0].as_ref().unwrap()[0]);
+/// assert_eq!(
+///     Err(bs58::decode::Error::InvalidVersion { ver: 0x05, expected_ver: 0x00 }),
+///     results[1]);
+/// ```
+#[cfg(all(feature = "check-core", feature = "sha2", feature = "alloc"))]
+pub fn verify_check_batch<I: AsRef<[u8]> + Sync>(
+    inputs: &[I],
+    expected_ver: Option<u8>,
+) -> alloc::vec::Vec<decode::Result<alloc::vec::Vec<u8>>> {
+    verify_check_batch_with_sha256::<DefaultSha256, I>(inputs, expected_ver)
+}
+
+/// Decode and verify a batch of [Base58Check][] strings as
+/// [`verify_check_batch`](crate::verify_check_batch()) does, using the given SHA-256
+/// implementation to verify the checksums.
+///
+/// See [`Sha256`] for more details.
+///
+/// [Base58Check]: https://en.bitcoin.it/wiki/Base58Check_encoding
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "check")]
+/// # fn main() {
+/// struct Zero;
+///
+/// impl bs58::Sha256 for Zero {
+///     fn digest(_: &[&[u8]]) -> [u8; 32] {
+///         [0; 32]
+///     }
+/// }
+///
+/// let encoded = bs58::encode([0x00, 0x31]).with_sha256::<Zero>().with_check().into_string();
+/// let results = bs58::verify_check_batch_with_sha256::<Zero, _>(&[&encoded], Some(0x00));
+/// assert_eq!(Ok(vec![0x00, 0x31]), results[0]);
+/// # }
+/// # #[cfg(not(feature = "check"))]
+/// # fn main() {}
+/// ```
+#[cfg(all(feature = "check-core", feature = "alloc"))]
+pub fn verify_check_batch_with_sha256<H: Sha256, I: AsRef<[u8]> + Sync>(
+    inputs: &[I],
+    expected_ver: Option<u8>,
+) -> alloc::vec::Vec<decode::Result<alloc::vec::Vec<u8>>> {
+    let verify = |input: &I| {
+        decode(input)
+            .with_sha256::<H>()
+            .with_check(expected_ver)
+            .into_vec()
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+        inputs.par_iter().map(verify).collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        inputs.iter().map(verify).collect()
+    }
+}
+
+/// Encode bytes onto a [`core::fmt::Write`] using a stack buffer, for use in `no_std`
+/// [`Display`](core::fmt::Display) implementations.
+///
+/// `bs58::write_base58!(output, input)`, where `output` is a `&mut impl Write`, is shorthand for
+/// [`bs58::encode(input).onto_fmt::<128>(output)`](encode::EncodeBuilder::onto_fmt), which
+/// supports inputs of up to 93 bytes. A different buffer size can be given as the third argument.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::fmt;
+///
+/// struct PublicKey([u8; 32]);
+///
+/// impl fmt::Display for PublicKey {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("key:")?;
+///         bs58::write_base58!(f, self.0)
+///     }
+/// }
+///
+/// assert_eq!(
+///     "key:2gPihUTjt3FJqf1VpidgrY5cZ6PuyMccGVwQHRfjMPZG",
+///     PublicKey([
+///         0x18, 0xf3, 0x06, 0xdf, 0xe6, 0x99, 0xd2, 0x08, 0x5c, 0x89, 0x7b, 0x43, 0xa4, 0xc5,
+///         0x4f, 0xc4, 0x7d, 0x2b, 0xb7, 0x55, 0x67, 0x5b, 0xe8, 0xa7, 0x49, 0x83, 0x68, 0x83,
+///         0x00, 0x65, 0xd6, 0xe7,
+///     ])
+///     .to_string(),
+/// );
+/// ```
+///
+/// ## Changing the buffer size
+///
+/// ```rust
+/// let mut output = String::new();
+/// bs58::write_base58!(&mut output, [0xff; 256], 350)?;
+/// assert_eq!(350, output.len());
+/// assert!(bs58::write_base58!(&mut output, [0xff; 256]).is_err());
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+#[macro_export]
+macro_rules! write_base58 {
+    ($output:expr, $input:expr $(,)?) => {
+        $crate::write_base58!($output, $input, 128)
+    };
+    ($output:expr, $input:expr, $max_len:expr $(,)?) => {
+        $crate::encode($input).onto_fmt::<{ $max_len }>($output)
+    };
+}
+
+// Force a round-trip through the `const` encoder and decoder to make sure they stay consistent
+const _: () = {
+    const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn round_trip<const N: usize, const M: usize>(decoded: &[u8; N], encoded: &[u8; M]) {
+        let output: [u8; M] = encode(decoded.as_slice()).into_array_const_unwrap();
+        assert!(bytes_eq(&output, encoded));
+        let output: [u8; N] = decode(output.as_slice()).into_array_const_unwrap();
+        assert!(bytes_eq(&output, decoded));
+    }
+
+    round_trip(&[], b"");
+    round_trip(&[0x61], b"2g");
+    round_trip(&[0x00, 0x00, 0x00], b"111");
+    round_trip(
+        &[0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+        b"he11owor1d",
+    );
+    round_trip(&[0xff; 10], b"FPBt6CHo3fovdL");
+};
@@ -0,0 +1,60 @@
+//! Serialize and deserialize fixed-size byte arrays as base58 strings, for use with
+//! `#[serde(with = "bs58::serde_fixed")]`.
+//!
+//! The array length is taken from the field type, deserialization fails if the string doesn't
+//! decode to exactly that many bytes, catching malformed keys at the serde boundary.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Wallet {
+//!     #[serde(with = "bs58::serde_fixed")]
+//!     key: [u8; 8],
+//! }
+//!
+//! let wallet: Wallet = serde_json::from_str(r#"{ "key": "he11owor1d" }"#)?;
+//! assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], wallet.key);
+//! assert_eq!(r#"{"key":"he11owor1d"}"#, serde_json::to_string(&wallet)?);
+//!
+//! assert!(serde_json::from_str::<Wallet>(r#"{ "key": "he11o" }"#).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::fmt;
+
+use alloc::vec::Vec;
+
+use serde::{de, Deserializer, Serializer};
+
+/// Serialize the array as a base58 string.
+pub fn serialize<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&crate::encode(bytes).into_string())
+}
+
+/// Deserialize a base58 string which must decode to exactly `N` bytes.
+pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    deserializer.deserialize_str(Visitor)
+}
+
+struct Visitor<const N: usize>;
+
+impl<const N: usize> de::Visitor<'_> for Visitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a base58 string encoding {} bytes", N)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<[u8; N], E> {
+        let decoded = crate::decode(value).into_vec().map_err(E::custom)?;
+        decoded
+            .try_into()
+            .map_err(|decoded: Vec<u8>| E::invalid_length(decoded.len(), &self))
+    }
+}
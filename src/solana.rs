@@ -0,0 +1,63 @@
+//! Helpers for [Solana][] public keys, which are 32 bytes encoded with the
+//! [`BITCOIN`](crate::Alphabet::BITCOIN) alphabet and no checksum.
+//!
+//! Decoding validates the length, so a truncated or overlong key is rejected here instead of
+//! being passed on to code expecting exactly 32 bytes.
+//!
+//! [Solana]: https://solana.com/docs/core/accounts
+//!
+//! # Examples
+//!
+//! ```rust
+//! let token_program = bs58::solana::decode_pubkey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")?;
+//! assert_eq!(
+//!     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+//!     bs58::solana::encode_pubkey(&token_program));
+//!
+//! // The system program is all zeros
+//! assert_eq!([0; 32], bs58::solana::decode_pubkey("11111111111111111111111111111111")?);
+//! # Ok::<(), bs58::decode::Error>(())
+//! ```
+
+use alloc::string::String;
+
+use crate::{decode, Alphabet};
+
+/// The length of a Solana public key in bytes.
+pub const PUBKEY_LEN: usize = 32;
+
+/// Encode a public key.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!("11111111111111111111111111111111", bs58::solana::encode_pubkey(&[0; 32]));
+/// ```
+pub fn encode_pubkey(pubkey: &[u8; PUBKEY_LEN]) -> String {
+    crate::encode(pubkey)
+        .with_alphabet(Alphabet::BITCOIN)
+        .into_string()
+}
+
+/// Decode a public key without allocating, returning
+/// [`Error::WrongLength`](decode::Error::WrongLength) if it decodes to fewer than [`PUBKEY_LEN`]
+/// bytes, or [`Error::BufferTooSmall`](decode::Error::BufferTooSmall) if it decodes to more.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!([0; 32], bs58::solana::decode_pubkey("11111111111111111111111111111111")?);
+///
+/// assert_eq!(
+///     bs58::decode::Error::WrongLength { expected: 32, actual: 31 },
+///     bs58::solana::decode_pubkey("1111111111111111111111111111111").unwrap_err());
+/// # Ok::<(), bs58::decode::Error>(())
+/// ```
+pub fn decode_pubkey(pubkey: &str) -> decode::Result<[u8; PUBKEY_LEN]> {
+    let mut decoded = [0; PUBKEY_LEN];
+    crate::decode(pubkey)
+        .with_alphabet(Alphabet::BITCOIN)
+        .expect_len(PUBKEY_LEN)
+        .onto(&mut decoded)?;
+    Ok(decoded)
+}
@@ -0,0 +1,28 @@
+//! Helpers for [`uuid::Uuid`], using the fixed-width encoding of
+//! [`bs58::encode_uuid`](crate::encode_uuid).
+//!
+//! # Examples
+//!
+//! ```rust
+//! let id = uuid::Uuid::from_u128(0x936da01f_9abd_4d9d_80c7_02af85c822a8);
+//! assert_eq!("KCtqn2CDK64hXvWfYwhRnP", bs58::uuid::encode(&id));
+//! assert_eq!(id, bs58::uuid::decode("KCtqn2CDK64hXvWfYwhRnP")?);
+//! # Ok::<(), bs58::decode::Error>(())
+//! ```
+
+use alloc::string::String;
+
+use ::uuid::Uuid;
+
+/// Encode a UUID as [`UUID_ENCODED_LEN`](crate::UUID_ENCODED_LEN) characters.
+pub fn encode(uuid: &Uuid) -> String {
+    crate::encode_uuid(uuid.as_u128())
+}
+
+/// Decode a UUID encoded by [`encode`].
+///
+/// See the documentation for [`bs58::decode_uuid`](crate::decode_uuid) for an explanation of
+/// the errors that may occur.
+pub fn decode<I: AsRef<[u8]>>(input: I) -> crate::decode::Result<Uuid> {
+    crate::decode_uuid(input).map(Uuid::from_u128)
+}
@@ -0,0 +1,133 @@
+//! Checks the number of heap allocations made by encoding and decoding, to catch regressions that
+//! add unnecessary allocations.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Counts allocations made on the current thread, so tests running in parallel don't interfere.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn count() {
+    // May be unavailable while the thread is being torn down
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count();
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made while running `f`.
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    drop(result);
+    after - before
+}
+
+const DECODED: [u8; 8] = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+const ENCODED: &str = "he11owor1d";
+
+#[test]
+fn test_encode_allocations() {
+    let mut output = [0; 16];
+    assert_eq!(
+        0,
+        allocations(|| bs58::encode(DECODED).onto(&mut output[..]).unwrap())
+    );
+    assert_eq!(1, allocations(|| bs58::encode(DECODED).into_string()));
+    assert_eq!(1, allocations(|| bs58::encode(DECODED).into_vec()));
+
+    let mut output = String::with_capacity(16);
+    assert_eq!(
+        0,
+        allocations(|| bs58::encode(DECODED).overwrite(&mut output).unwrap())
+    );
+}
+
+#[test]
+fn test_decode_allocations() {
+    let mut output = [0; 16];
+    assert_eq!(
+        0,
+        allocations(|| bs58::decode(ENCODED).onto(&mut output[..]).unwrap())
+    );
+    assert_eq!(1, allocations(|| bs58::decode(ENCODED).into_vec().unwrap()));
+    assert_eq!(1, allocations(|| bs58::decode(ENCODED).into_arc().unwrap()));
+    assert_eq!(
+        1,
+        allocations(|| bs58::decode(ENCODED).into_exact_boxed_slice().unwrap())
+    );
+    assert_eq!(
+        0,
+        allocations(|| bs58::matches(ENCODED, &DECODED, bs58::Alphabet::DEFAULT))
+    );
+    assert_eq!(
+        0,
+        allocations(|| {
+            bs58::solana::decode_pubkey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap()
+        })
+    );
+
+    let mut output = Vec::with_capacity(16);
+    assert_eq!(
+        0,
+        allocations(|| bs58::decode(ENCODED).onto(&mut output).unwrap())
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_check_allocations() {
+    let encoded = bs58::encode(DECODED).with_check().into_string();
+
+    let mut output = [0; 16];
+    assert_eq!(
+        0,
+        allocations(|| bs58::encode(DECODED)
+            .with_check()
+            .onto(&mut output[..])
+            .unwrap())
+    );
+    assert_eq!(
+        1,
+        allocations(|| bs58::encode(DECODED).with_check().into_string())
+    );
+    assert_eq!(
+        0,
+        allocations(|| bs58::decode(&encoded)
+            .with_check(None)
+            .onto(&mut output[..])
+            .unwrap())
+    );
+    assert_eq!(
+        1,
+        allocations(|| bs58::decode(&encoded).with_check(None).into_vec().unwrap())
+    );
+}
@@ -0,0 +1,69 @@
+//! Conformance against the encode/decode test vectors shipped with Bitcoin Core.
+//!
+//! `data/base58_encode_decode.json` is copied unmodified from
+//! <https://github.com/bitcoin/bitcoin/blob/master/src/test/data/base58_encode_decode.json>,
+//! Copyright (c) The Bitcoin Core developers, distributed under the MIT software license.
+//!
+//! Bitcoin Core's decoder also skips surrounding whitespace, which this crate deliberately
+//! doesn't, so only the invalid inputs that don't rely on that are checked here.
+
+#![cfg(feature = "bitcoin-vectors")]
+
+const VECTORS: &str = include_str!("data/base58_encode_decode.json");
+
+fn vectors() -> Vec<(Vec<u8>, String)> {
+    let vectors: Vec<(String, String)> = serde_json::from_str(VECTORS).unwrap();
+    vectors
+        .into_iter()
+        .map(|(hex, encoded)| {
+            let decoded = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                .collect();
+            (decoded, encoded)
+        })
+        .collect()
+}
+
+#[test]
+fn test_bitcoin_core_encode() {
+    for (decoded, encoded) in vectors() {
+        assert_eq!(encoded, bs58::encode(&decoded).into_string());
+        assert_eq!(
+            encoded,
+            bs58::encode(&decoded)
+                .with_alphabet(bs58::Alphabet::BITCOIN)
+                .into_string()
+        );
+    }
+}
+
+#[test]
+fn test_bitcoin_core_decode() {
+    for (decoded, encoded) in vectors() {
+        assert_eq!(Ok(decoded.clone()), bs58::decode(&encoded).into_vec());
+
+        let mut output = vec![0xFF; decoded.len()];
+        assert_eq!(
+            Ok(decoded.len()),
+            bs58::decode(&encoded).onto(&mut output[..])
+        );
+        assert_eq!(decoded, output);
+    }
+}
+
+#[test]
+fn test_bitcoin_core_decode_invalid() {
+    for input in [
+        "invalid",
+        "invalid\0",
+        "\0invalid",
+        "bad0IOl",
+        "goodbad0IOl",
+        "good\0bad0IOl",
+    ] {
+        assert!(bs58::decode(input).into_vec().is_err(), "{input:?}");
+    }
+
+    assert_eq!(Ok(vec![0x76, 0x83, 0x20]), bs58::decode("good").into_vec());
+}
@@ -1,6 +1,6 @@
 mod cases;
 
-#[cfg(feature = "check")]
+#[cfg(any(feature = "check", feature = "cb58"))]
 use assert_matches::assert_matches;
 
 #[test]
@@ -8,6 +8,8 @@ fn test_decode() {
     for &(val, s) in cases::TEST_CASES.iter() {
         assert_eq!(val.to_vec(), bs58::decode(s).into_vec().unwrap());
 
+        assert_eq!(val, bs58::decode(s).into_cursor().unwrap().get_ref());
+
         const PREFIX: &[u8] = &[0, 1, 2];
 
         {
@@ -16,6 +18,11 @@ fn test_decode() {
             assert_eq!((PREFIX, val), vec.split_at(3));
         }
 
+        {
+            let (array, len) = bs58::decode(s).into_inline::<128>().unwrap();
+            assert_eq!(val, &array[..len]);
+        }
+
         {
             let vec = bs58::decode(s.as_bytes()).into_array_const_unwrap::<128>();
             let mut check = [0; 128];
@@ -23,6 +30,15 @@ fn test_decode() {
             assert_eq!(vec, check);
         }
 
+        {
+            let mut vec = PREFIX.to_vec();
+            assert_eq!(
+                Ok(val.len()),
+                bs58::decode(s).replace_target().onto(&mut vec)
+            );
+            assert_eq!(val, vec.as_slice());
+        }
+
         #[cfg(feature = "smallvec")]
         {
             let mut vec = smallvec::SmallVec::<[u8; 36]>::from(PREFIX);
@@ -30,6 +46,14 @@ fn test_decode() {
             assert_eq!((PREFIX, val), vec.split_at(3));
         }
 
+        #[cfg(feature = "bumpalo")]
+        {
+            let arena = bumpalo::Bump::new();
+            let mut vec = bumpalo::collections::Vec::from_iter_in(PREFIX.iter().copied(), &arena);
+            assert_eq!(Ok(val.len()), bs58::decode(s).onto(&mut vec));
+            assert_eq!((PREFIX, val), vec.split_at(3));
+        }
+
         #[cfg(feature = "tinyvec")]
         {
             {
@@ -61,10 +85,52 @@ fn test_decode() {
                 assert_eq!(Ok(val.len()), bs58::decode(s).onto(&mut vec));
                 assert_eq!((PREFIX, val), vec.split_at(3));
             }
+
+            {
+                let mut vec = tinyvec::TinyVec::<[u8; 36]>::from(PREFIX);
+                let res = bs58::decode(s).replace_target().onto(&mut vec);
+                assert_eq!(Ok(val.len()), res);
+                assert_eq!(val, vec.as_slice());
+            }
+        }
+    }
+}
+
+/// Every string of alphabet characters is a canonical encoding, there is no padding to reject.
+#[test]
+fn test_decode_trailing_zero_is_significant() {
+    for &(_, s) in cases::TEST_CASES.iter() {
+        for input in [format!("{}1", s), format!("1{}", s), format!("{}11", s)] {
+            let decoded = bs58::decode(&input).into_vec().unwrap();
+            assert_eq!(input, bs58::encode(decoded).into_string());
         }
     }
 }
 
+/// The first character of the alphabet is always the zero digit, used for leading zero bytes.
+#[test]
+fn test_decode_zero_is_first_character() {
+    let alpha =
+        bs58::Alphabet::new(b"z123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxy").unwrap();
+    assert_eq!(
+        vec![0x00, 0x00, 0x01, 0x00],
+        bs58::decode("zz4Q")
+            .with_alphabet(&alpha)
+            .into_vec()
+            .unwrap()
+    );
+    assert_eq!(
+        "zz4Q",
+        bs58::encode([0x00, 0x00, 0x01, 0x00])
+            .with_alphabet(&alpha)
+            .into_string()
+    );
+    assert_eq!(
+        bs58::decode("11").with_alphabet(&alpha).into_vec().unwrap(),
+        vec![0x3b]
+    );
+}
+
 #[test]
 fn test_decode_small_buffer_err() {
     let mut output = [0; 2];
@@ -74,6 +140,79 @@ fn test_decode_small_buffer_err() {
     );
 }
 
+/// Verify the upfront length check never rejects input that would fit exactly.
+#[test]
+fn test_decode_exact_buffer() {
+    let mut output = [0u8; 256];
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(
+            Ok(val.len()),
+            bs58::decode(s).onto(&mut output[..val.len()])
+        );
+        if !val.is_empty() {
+            assert_eq!(
+                Err(bs58::decode::Error::BufferTooSmall),
+                bs58::decode(s).onto(&mut output[..val.len() - 1])
+            );
+        }
+    }
+
+    for len in 1..256 {
+        for input in ["z".repeat(len), format!("2{}", "1".repeat(len - 1))] {
+            let decoded = bs58::decode(&input).into_vec().unwrap();
+            assert_eq!(
+                Ok(decoded.len()),
+                bs58::decode(&input).onto(&mut output[..decoded.len()])
+            );
+        }
+    }
+}
+
+/// Verify the limb and byte at a time decoders agree, including on errors, whichever is chosen.
+#[test]
+fn test_decode_limbs_match_bytes() {
+    let alpha = bs58::Alphabet::BITCOIN;
+    let mut inputs: Vec<String> = cases::TEST_CASES.iter().map(|&(_, s)| s.into()).collect();
+    for len in 1..=436 {
+        inputs.push("z".repeat(len));
+        inputs.push(format!("1{}", "2".repeat(len)));
+        inputs.push(format!("{}0", "z".repeat(len)));
+    }
+    for input in &inputs {
+        for output_len in [0, 4, 32, 320] {
+            let (mut limbs, mut bytes) = (vec![0; output_len], vec![0; output_len]);
+            let result = bs58::decode::decode_bytes_into(input.as_bytes(), &mut bytes, alpha);
+            assert_eq!(
+                result,
+                bs58::decode::decode_limbs_into(input.as_bytes(), &mut limbs, alpha),
+                "{}",
+                input
+            );
+            // The output is unspecified after an error
+            if let Ok(len) = result {
+                assert_eq!(bytes[..len], limbs[..len]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_decode_too_long_rejected_early() {
+    let mut output = [0; 4];
+    assert_eq!(
+        bs58::decode("z".repeat(10)).onto(&mut output),
+        Err(bs58::decode::Error::BufferTooSmall)
+    );
+    let input = format!("{}!", "z".repeat(10));
+    assert_eq!(
+        bs58::decode(input).onto(&mut output),
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '!',
+            index: 10,
+        })
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_decode_const_small_buffer_panic() {
@@ -87,6 +226,24 @@ fn test_decode_const_invalid_char_panic() {
     let _ = bs58::decode(sample.as_bytes()).into_array_const_unwrap::<32>();
 }
 
+#[test]
+fn test_decode_const_opt() {
+    const SMALL_BUFFER: Option<[u8; 2]> = bs58::decode(b"a3gV".as_slice()).into_array_const_opt();
+    const INVALID_CHAR: Option<[u8; 32]> =
+        bs58::decode(b"123456789abcd!efghij".as_slice()).into_array_const_opt();
+    const NON_ASCII: Option<[u8; 32]> = bs58::decode("he11o🇳🇿".as_bytes()).into_array_const_opt();
+    assert_eq!(None, SMALL_BUFFER);
+    assert_eq!(None, INVALID_CHAR);
+    assert_eq!(None, NON_ASCII);
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let array = bs58::decode(s.as_bytes())
+            .into_array_const_opt::<128>()
+            .unwrap();
+        assert_eq!(val, &array[..val.len()]);
+    }
+}
+
 #[test]
 fn test_decode_invalid_char() {
     let sample = "123456789abcd!efghij";
@@ -99,6 +256,134 @@ fn test_decode_invalid_char() {
     );
 }
 
+#[test]
+fn test_decode_lossy() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!((val.to_vec(), None), bs58::decode(s).into_vec_lossy());
+
+        for suffix in ["0", "!abc", "🇳🇿"] {
+            let input = format!("{}{}", s, suffix);
+            let (output, err) = bs58::decode(&input).into_vec_lossy();
+            assert_eq!(val.to_vec(), output);
+            assert_eq!(bs58::decode(&input).into_vec().unwrap_err(), err.unwrap());
+        }
+    }
+
+    // The recovered prefix is taken from the input prepared as for a full decode
+    let expected = bs58::decode("he11owor1d").into_vec().unwrap();
+    assert_eq!(
+        (
+            expected.clone(),
+            Some(bs58::decode::Error::InvalidCharacter {
+                character: '!',
+                index: 13
+            })
+        ),
+        bs58::decode("111he11owor1d!").padded().into_vec_lossy()
+    );
+    assert_eq!(
+        (
+            expected.clone(),
+            Some(bs58::decode::Error::InvalidCharacter {
+                character: '!',
+                index: 15
+            })
+        ),
+        bs58::decode("addr_he11owor1d!")
+            .strip_prefix("addr_")
+            .into_vec_lossy()
+    );
+    assert_eq!(
+        (Vec::new(), Some(bs58::decode::Error::MissingPrefix)),
+        bs58::decode("he11owor1d")
+            .strip_prefix("addr_")
+            .into_vec_lossy()
+    );
+
+    #[cfg(feature = "check")]
+    {
+        let (output, err) = bs58::decode("he11owor1e.45W3Aw")
+            .with_check_delimited('.')
+            .into_vec_lossy();
+        assert_eq!(bs58::decode("he11owor1e").into_vec().unwrap(), output);
+        assert_matches!(err, Some(bs58::decode::Error::InvalidChecksum { .. }));
+    }
+}
+
+#[test]
+fn test_decode_invalid_char_verbose() {
+    let err = bs58::decode("123456789abcd!efghij").into_vec().unwrap_err();
+    assert_eq!(
+        err.with_alphabet(bs58::Alphabet::DEFAULT).to_string(),
+        "provided string contained invalid character '!' at byte 13, valid characters are: \
+         123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    );
+
+    let err = bs58::decode("a3gV").onto(&mut [0; 2]).unwrap_err();
+    assert_eq!(
+        err.with_alphabet(bs58::Alphabet::DEFAULT).to_string(),
+        err.to_string()
+    );
+}
+
+#[test]
+fn test_decode_error_context() {
+    let input = "123456789abcd!efghij";
+    let err = bs58::decode(input).into_vec().unwrap_err();
+    assert_eq!(Some("...[!]...".to_owned()), err.context(input, 0));
+    assert_eq!(Some("...d[!]e...".to_owned()), err.context(input, 1));
+    assert_eq!(
+        Some("123456789abcd[!]efghij".to_owned()),
+        err.context(input, 13)
+    );
+    assert_eq!(
+        Some("123456789abcd[!]efghij".to_owned()),
+        err.context(input, 100)
+    );
+
+    let input = "\u{e9}1";
+    let err = bs58::decode(input).into_vec().unwrap_err();
+    assert_eq!(Some("[\u{e9}]1".to_owned()), err.context(input, 1));
+
+    // The index has to refer to a character in the given input
+    let err = bs58::decode::Error::NonAsciiCharacter { index: 1 };
+    assert_eq!(None, err.context(input, 1));
+    assert_eq!(None, err.context("", 1));
+    assert_eq!(None, bs58::decode::Error::MissingPrefix.context(input, 1));
+}
+
+#[test]
+fn test_decode_invalid_char_case_suggestion() {
+    // 'l' is only valid in uppercase for the default alphabet
+    let err = bs58::decode("he11oworld").into_vec().unwrap_err();
+    let verbose = err.with_alphabet(bs58::Alphabet::DEFAULT);
+    assert_eq!(Some('L'), verbose.suggestion());
+    assert_eq!(
+        verbose.to_string(),
+        "provided string contained invalid character 'l' at byte 8, did you mean 'L'?",
+    );
+
+    // Only one case of each letter is excluded from the default alphabet
+    let err = bs58::decode("1O").into_vec().unwrap_err();
+    assert_eq!(
+        Some('o'),
+        err.with_alphabet(bs58::Alphabet::DEFAULT).suggestion()
+    );
+
+    // Non-letters never get a suggestion
+    let err = bs58::decode("0").into_vec().unwrap_err();
+    assert_eq!(
+        None,
+        err.with_alphabet(bs58::Alphabet::DEFAULT).suggestion()
+    );
+
+    let err = bs58::decode("a3gV").onto(&mut [0; 2]).unwrap_err();
+    assert_eq!(
+        None,
+        err.with_alphabet(bs58::Alphabet::DEFAULT).suggestion()
+    );
+}
+
 #[test]
 #[cfg(feature = "check")]
 fn test_decode_check() {
@@ -128,6 +413,309 @@ fn test_check_ver_failed() {
     assert_matches!(d.unwrap_err(), bs58::decode::Error::InvalidVersion { .. });
 }
 
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_over() {
+    for &(val, s) in cases::CHECK_TEST_CASES.iter() {
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode(s).with_check_over(..).into_vec().unwrap()
+        );
+    }
+
+    let payload = [0, 1, 2, 3, 4, 5, 6, 7];
+    let s = "1FVk6iLh9uSXnft";
+    assert_eq!(
+        payload.to_vec(),
+        bs58::decode(s).with_check_over(2..5).into_vec().unwrap()
+    );
+    assert_eq!(
+        payload.to_vec(),
+        bs58::decode(s).with_check_over(2..=4).into_vec().unwrap()
+    );
+    assert_matches!(
+        bs58::decode(s).with_check_over(..).into_vec().unwrap_err(),
+        bs58::decode::Error::InvalidChecksum { .. }
+    );
+    assert_matches!(
+        bs58::decode(s)
+            .with_check(Some(1))
+            .with_check_over(2..5)
+            .into_vec()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidVersion { ver: 0, .. }
+    );
+
+    for (start, end) in [(0, 9), (5, 4), (9, 9)] {
+        assert_eq!(
+            bs58::decode(s)
+                .with_check_over(start..end)
+                .into_vec()
+                .unwrap_err(),
+            bs58::decode::Error::InvalidChecksumRange { start, end, len: 8 }
+        );
+    }
+
+    // Other checksum schemes are kept rather than replaced with Base58Check
+    let delimited = bs58::encode(payload)
+        .with_check_delimited('_')
+        .into_string();
+    assert_eq!(
+        payload.to_vec(),
+        bs58::decode(&delimited)
+            .with_check_delimited('_')
+            .with_check_over(2..5)
+            .into_vec()
+            .unwrap()
+    );
+    #[cfg(feature = "cb58")]
+    {
+        let cb58 = bs58::encode(payload).as_cb58(None).into_string();
+        assert_eq!(
+            payload.to_vec(),
+            bs58::decode(&cb58)
+                .as_cb58(None)
+                .with_check_over(2..5)
+                .into_vec()
+                .unwrap()
+        );
+    }
+}
+
+/// Verify hashing the payload while decoding gives exactly the same results as hashing it after.
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_incremental() {
+    // Hashes the payload while decoding it
+    struct Incremental;
+
+    impl bs58::Sha256 for Incremental {
+        fn digest(data: &[&[u8]]) -> [u8; 32] {
+            <bs58::DefaultSha256 as bs58::Sha256>::digest(data)
+        }
+
+        fn digest_incremental(
+            produce: &mut dyn FnMut(&mut bs58::Sha256Update<'_>),
+        ) -> Option<[u8; 32]> {
+            use sha2::Digest;
+
+            let mut hasher = sha2::Sha256::new();
+            produce(&mut |part| hasher.update(part));
+            Some(hasher.finalize().into())
+        }
+    }
+
+    // Lengths around the 64 byte blocks, and long enough to use the byte at a time decoder
+    for len in [0, 1, 59, 60, 61, 64, 65, 128, 300, 400] {
+        for zeros in [0, 1, 70] {
+            let mut payload = vec![0; zeros];
+            payload.extend((0..len).map(|i| (i * 7 + 3) as u8));
+            let encoded = bs58::encode(&payload).with_check().into_string();
+            let mut corrupted = bs58::decode(&encoded).into_vec().unwrap();
+            let last = corrupted.len() - 1;
+            corrupted[last] ^= 1;
+            let corrupted = bs58::encode(corrupted).into_string();
+
+            for input in [&encoded, &corrupted] {
+                assert_eq!(
+                    bs58::decode(input)
+                        .with_check(None)
+                        .with_sha256::<Incremental>()
+                        .into_vec(),
+                    bs58::decode(input).with_check(None).into_vec()
+                );
+                let payload_len = payload.len();
+                for (start, end) in [(0, payload_len), (1, 65), (64, payload_len), (3, 2)] {
+                    assert_eq!(
+                        bs58::decode(input)
+                            .with_check_over(start..end)
+                            .with_sha256::<Incremental>()
+                            .into_vec(),
+                        bs58::decode(input).with_check_over(start..end).into_vec()
+                    );
+                }
+            }
+            assert_eq!(
+                Ok(payload),
+                bs58::decode(&encoded).with_check(None).into_vec()
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "percent-encoding")]
+fn test_decode_percent_encoded() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let encoded: String = s.bytes().map(|b| format!("%{:02X}", b)).collect();
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode(&encoded).percent_decode().into_vec().unwrap()
+        );
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode(s).percent_decode().into_vec().unwrap()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "percent-encoding")]
+fn test_decode_percent_encoded_err() {
+    for (input, index) in [("%", 0), ("a3%g", 2), ("a3gV%4", 4), ("%zz", 0)] {
+        assert_eq!(
+            bs58::decode(input).percent_decode().into_vec().unwrap_err(),
+            bs58::decode::Error::InvalidPercentEncoding { index }
+        );
+    }
+    assert_eq!(
+        bs58::decode("a3%21gV")
+            .percent_decode()
+            .into_vec()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '!',
+            index: 2
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_version_bytes() {
+    let (version, payload, s) = cases::BIP32_XPUB;
+    let decoded = bs58::decode(s)
+        .with_check_version_bytes(version)
+        .into_vec()
+        .unwrap();
+    assert_eq!((version, payload), decoded.split_at(version.len()));
+
+    assert_eq!(
+        bs58::decode(s)
+            .with_check_version_bytes(&[0x04, 0x88, 0xad, 0xe4])
+            .into_vec()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidVersion {
+            ver: 0xb2,
+            expected_ver: 0xad
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_trailing_version() {
+    let (payload, version, s) = cases::CHECK_TRAILING_VERSION;
+    let decoded = bs58::decode(s)
+        .with_check_trailing_version(version)
+        .into_vec()
+        .unwrap();
+    assert_eq!((payload, &[version][..]), decoded.split_at(payload.len()));
+
+    // The leading byte isn't the version
+    assert_eq!(
+        bs58::decode(s).with_check(Some(version)).into_vec(),
+        Err(bs58::decode::Error::InvalidVersion {
+            ver: 0x01,
+            expected_ver: version
+        })
+    );
+
+    assert_eq!(
+        bs58::decode(s).with_check_trailing_version(0x01).into_vec(),
+        Err(bs58::decode::Error::InvalidVersion {
+            ver: version,
+            expected_ver: 0x01
+        })
+    );
+
+    // The last builder method decides where the version is
+    assert_eq!(
+        bs58::decode(s)
+            .with_check_trailing_version(version)
+            .with_check(Some(0x01))
+            .into_vec()
+            .unwrap(),
+        decoded
+    );
+
+    // No room for a version before the checksum
+    let s = bs58::encode([]).with_check().into_string();
+    assert_eq!(
+        bs58::decode(s)
+            .with_check_trailing_version(version)
+            .into_vec(),
+        Err(bs58::decode::Error::NoChecksum)
+    );
+}
+
+#[test]
+fn test_decode_ignoring_whitespace() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let spaced: String = s.chars().flat_map(|c| [c, ' ']).collect();
+        let (decoded, whitespace) = bs58::decode(&spaced)
+            .into_vec_ignoring_whitespace()
+            .unwrap();
+        assert_eq!(val, &*decoded);
+        assert_eq!(
+            (0..s.len()).map(|i| i * 2 + 1).collect::<Vec<_>>(),
+            whitespace
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("\t a 3\r\ngV  !")
+            .into_vec_ignoring_whitespace()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '!',
+            index: 11
+        }
+    );
+    assert_eq!(
+        bs58::decode(" a3\n🇳🇿")
+            .into_vec_ignoring_whitespace()
+            .unwrap_err(),
+        bs58::decode::Error::NonAsciiCharacter { index: 4 }
+    );
+}
+
+#[test]
+fn test_decode_length_prefixed() {
+    let mut records = Vec::new();
+    for &(val, _) in cases::TEST_CASES.iter() {
+        records.extend(bs58::encode(val).into_length_prefixed_vec());
+    }
+
+    let mut remaining = &records[..];
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let (decoded, consumed) = bs58::decode(remaining).into_length_prefixed_vec().unwrap();
+        assert_eq!(val, &*decoded);
+        assert_eq!(4 + s.len(), consumed);
+        remaining = &remaining[consumed..];
+    }
+    assert!(remaining.is_empty());
+
+    assert_eq!(
+        bs58::decode(&records[..3])
+            .into_length_prefixed_vec()
+            .unwrap_err(),
+        bs58::decode::Error::InputTooShort {
+            expected: 4,
+            actual: 3
+        }
+    );
+    assert_eq!(
+        bs58::decode(b"\0\0\0\x02a!")
+            .into_length_prefixed_vec()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '!',
+            index: 1
+        }
+    );
+}
+
 #[test]
 fn append() {
     let mut buf = b"hello world".to_vec();
@@ -135,9 +723,1860 @@ fn append() {
     assert_eq!(b"hello world!", buf.as_slice());
 }
 
+#[test]
+fn replace() {
+    let mut buf = b"hello world".to_vec();
+    bs58::decode("a").replace_target().onto(&mut buf).unwrap();
+    assert_eq!(b"!", buf.as_slice());
+
+    let mut buf = b"hello world".to_owned();
+    bs58::decode("a").replace_target().onto(&mut buf).unwrap();
+    assert_eq!(b"!ello world", buf.as_ref());
+}
+
 #[test]
 fn no_append() {
     let mut buf = b"hello world".to_owned();
     bs58::decode("a").onto(buf.as_mut()).unwrap();
     assert_eq!(b"!ello world", buf.as_ref());
 }
+
+#[test]
+fn test_decode_vec_no_realloc() {
+    // Decoding into a vector with `input.len()` capacity must always be enough
+    for len in [0, 1, 10, 31, 32, 33, 100, 255, 256, 1000, 4096] {
+        for fill in [0x00, 0x01, 0x7f, 0xff] {
+            let s = bs58::encode(vec![fill; len]).into_string();
+            let mut output = Vec::with_capacity(s.len());
+            let ptr = output.as_ptr();
+            bs58::decode(&s).onto(&mut output).unwrap();
+            assert_eq!(vec![fill; len], output);
+            assert_eq!(ptr, output.as_ptr());
+            assert_eq!(s.len(), output.capacity());
+        }
+    }
+}
+
+#[test]
+fn test_decode_vec_reserved_len() {
+    // Non-zero digits decode to about 0.73 bytes each, so the vector should be sized below the
+    // input length, while still never reallocating
+    for len in [10, 32, 100, 1000, 4096] {
+        for fill in [0x01, 0x7f, 0xff] {
+            let s = bs58::encode(vec![fill; len]).into_string();
+            let mut output = Vec::new();
+            bs58::decode(&s).onto(&mut output).unwrap();
+            assert_eq!(vec![fill; len], output);
+            assert!(
+                output.capacity() < s.len(),
+                "{} < {}",
+                output.capacity(),
+                s.len()
+            );
+            assert!(output.capacity() <= len + len / 100 + 1);
+        }
+    }
+
+    // Leading zeros decode to one byte each
+    let s = bs58::encode(vec![0; 100]).into_string();
+    let output = bs58::decode(&s).into_vec().unwrap();
+    assert_eq!(vec![0; 100], output);
+}
+
+#[test]
+fn test_decode_exact_prealloc() {
+    for len in [8, 10, 32, 100, 1000, 4096] {
+        for fill in [0x00, 0x01, 0xff] {
+            let s = bs58::encode(vec![fill; len]).into_string();
+            let output = bs58::decode(&s).exact_prealloc().into_vec().unwrap();
+            assert_eq!(vec![fill; len], output);
+            assert_eq!(len, output.capacity());
+        }
+    }
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let mut output = b"hello ".to_vec();
+        let len = bs58::decode(s).exact_prealloc().onto(&mut output).unwrap();
+        assert_eq!(val.len(), len);
+        assert_eq!((&b"hello "[..], val), output.split_at(6));
+
+        let mut output = [0xFF; 256];
+        assert_eq!(
+            Ok(val.len()),
+            bs58::decode(s).exact_prealloc().onto(&mut output)
+        );
+        assert_eq!(val, &output[..val.len()]);
+    }
+
+    if let Some(&(_, s)) = cases::TEST_CASES.iter().find(|(val, _)| val.len() > 2) {
+        assert_eq!(
+            Err(bs58::decode::Error::BufferTooSmall),
+            bs58::decode(s).exact_prealloc().onto(&mut [0; 2])
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("he11o world")
+            .exact_prealloc()
+            .into_vec()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        }
+    );
+}
+
+#[test]
+fn test_decode_for_each_byte() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let mut output = Vec::new();
+        bs58::decode(s).for_each_byte(|b| output.push(b)).unwrap();
+        assert_eq!(val, &*output);
+    }
+
+    let mut called = false;
+    assert_eq!(
+        bs58::decode("he11o0").for_each_byte(|_| called = true),
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 5
+        })
+    );
+    assert!(!called);
+}
+
+#[test]
+fn test_cmp() {
+    let alpha = bs58::Alphabet::DEFAULT;
+    for &(a_val, a) in cases::TEST_CASES.iter() {
+        for &(b_val, b) in cases::TEST_CASES.iter() {
+            let a_zeros = a_val.iter().take_while(|&&b| b == 0).count();
+            let b_zeros = b_val.iter().take_while(|&&b| b == 0).count();
+            let (a_num, b_num) = (&a_val[a_zeros..], &b_val[b_zeros..]);
+            let expected = a_num
+                .len()
+                .cmp(&b_num.len())
+                .then(a_num.cmp(b_num))
+                .then(a_val.len().cmp(&b_val.len()));
+            assert_eq!(expected, bs58::cmp(a, b, alpha).unwrap(), "{a} {b}");
+        }
+    }
+
+    assert_eq!(
+        bs58::cmp("he11o", "zz0", alpha).unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 2
+        }
+    );
+    assert_eq!(
+        bs58::cmp("z🇳", "zz0", alpha).unwrap_err(),
+        bs58::decode::Error::NonAsciiCharacter { index: 1 }
+    );
+}
+
+#[test]
+fn test_decode_into_validated() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let (encoded, decoded) = bs58::decode(s).into_validated().unwrap();
+        assert!(std::ptr::eq(s, encoded));
+        assert_eq!(val, &*decoded);
+    }
+
+    assert_eq!(
+        bs58::decode("he11o0").into_validated().unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 5
+        }
+    );
+}
+
+#[test]
+fn test_decode_os() {
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(val, &*bs58::decode_os(OsStr::new(s)).into_vec().unwrap());
+        assert_eq!(val, &*bs58::decode_os(Path::new(s)).into_vec().unwrap());
+        assert_eq!(val, &*bs58::decode_os(s).into_vec().unwrap());
+    }
+
+    assert_eq!(
+        bs58::decode_os("he11o🇳🇿").into_vec().unwrap_err(),
+        bs58::decode::Error::NonAsciiCharacter { index: 5 }
+    );
+}
+
+#[test]
+fn test_decode_padded() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let zeros = val.iter().take_while(|b| **b == 0).count();
+        let padded = bs58::encode(val).padded_to(s.len() + 5).into_string();
+        assert_eq!(
+            &val[zeros..],
+            &*bs58::decode(&padded).padded().into_vec().unwrap()
+        );
+        assert_eq!(
+            &val[zeros..],
+            &*bs58::decode(s).padded().into_vec().unwrap()
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("111he11o0").padded().into_vec().unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 8
+        }
+    );
+}
+
+#[test]
+fn test_decode_strip_prefix() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let prefixed = format!("addr_{}", s);
+        assert_eq!(
+            val,
+            &*bs58::decode(&prefixed)
+                .strip_prefix("addr_")
+                .into_vec()
+                .unwrap()
+        );
+        assert_eq!(val, &*bs58::decode(s).strip_prefix("").into_vec().unwrap());
+    }
+
+    for input in ["", "add", "addr", "xaddr_he11o", "ADDR_he11o"] {
+        assert_eq!(
+            bs58::decode(input)
+                .strip_prefix("addr_")
+                .into_vec()
+                .unwrap_err(),
+            bs58::decode::Error::MissingPrefix
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("addr_11he11o🇳🇿")
+            .strip_prefix("addr_")
+            .padded()
+            .into_vec()
+            .unwrap_err(),
+        bs58::decode::Error::NonAsciiCharacter { index: 12 }
+    );
+}
+
+#[test]
+fn test_decode_into_hex_string() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let hex: String = val.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(hex, bs58::decode(s).into_hex_string().unwrap());
+        assert_eq!(
+            hex.to_uppercase(),
+            bs58::decode(s).into_upper_hex_string().unwrap()
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("he11o0").into_hex_string().unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 5
+        }
+    );
+}
+
+#[test]
+fn test_decode_aliased_zero() {
+    let alpha =
+        bs58::Alphabet::builder(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+            .alias(b'0', b'1')
+            .build()
+            .unwrap();
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let aliased = s.replace('1', "0");
+        assert_eq!(
+            val,
+            &*bs58::decode(&aliased)
+                .with_alphabet(&alpha)
+                .into_vec()
+                .unwrap()
+        );
+        let mut output = [0; 128];
+        let len = bs58::decode(&aliased)
+            .with_alphabet(&alpha)
+            .onto(&mut output)
+            .unwrap();
+        assert_eq!(val, &output[..len]);
+    }
+}
+
+#[test]
+fn test_decode_length_boundaries() {
+    for len in [10, 11, 21, 22, 43, 44] {
+        for c in ["1", "2", "z"] {
+            let s = c.repeat(len);
+            let decoded = bs58::decode(&s).into_vec().unwrap();
+            assert_eq!(s, bs58::encode(&decoded).into_string());
+
+            let mut output = [0; 44];
+            let len = bs58::decode(&s).onto(&mut output).unwrap();
+            assert_eq!(decoded, &output[..len]);
+        }
+    }
+}
+
+#[test]
+fn test_decode_into_vec_prefix() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        for rest in ["", ":rest", "0", " ", "🇳🇿"] {
+            let input = format!("{}{}", s, rest);
+            assert_eq!(
+                (val.to_vec(), s.len()),
+                bs58::decode(&input).into_vec_prefix().unwrap()
+            );
+        }
+    }
+
+    assert_eq!(
+        (vec![0x61], 7),
+        bs58::decode("addr_2g_3")
+            .strip_prefix("addr_")
+            .into_vec_prefix()
+            .unwrap()
+    );
+    assert_eq!(
+        bs58::decode("2g_3")
+            .strip_prefix("addr_")
+            .into_vec_prefix()
+            .unwrap_err(),
+        bs58::decode::Error::MissingPrefix
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_into_vec_prefix() {
+    for &(val, s) in cases::CHECK_TEST_CASES.iter() {
+        let input = format!("{}-{}", s, s);
+        assert_eq!(
+            (val.to_vec(), s.len()),
+            bs58::decode(&input)
+                .with_check(None)
+                .into_vec_prefix()
+                .unwrap()
+        );
+    }
+
+    assert_matches!(
+        bs58::decode("3vQB7B6MrGQZaxCuFg4oi-")
+            .with_check(None)
+            .into_vec_prefix()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidChecksum { .. }
+    );
+}
+
+#[test]
+#[cfg(any(feature = "check", feature = "cb58"))]
+fn test_with_sha256() {
+    struct Zero;
+
+    impl bs58::Sha256 for Zero {
+        fn digest(_: &[&[u8]]) -> [u8; 32] {
+            [0; 32]
+        }
+    }
+
+    let input = [1, 2, 3];
+    let unchecked = bs58::encode([1, 2, 3, 0, 0, 0, 0]).into_string();
+
+    #[cfg(feature = "check")]
+    {
+        let encoded = bs58::encode(input)
+            .with_check()
+            .with_sha256::<Zero>()
+            .into_string();
+        assert_eq!(unchecked, encoded);
+        assert_eq!(
+            input.to_vec(),
+            bs58::decode(&encoded)
+                .with_check(None)
+                .with_sha256::<Zero>()
+                .into_vec()
+                .unwrap()
+        );
+        assert_matches!(
+            bs58::decode(&encoded).with_check(None).into_vec(),
+            Err(bs58::decode::Error::InvalidChecksum { .. })
+        );
+
+        let scheme = bs58::decode::CheckScheme::Check;
+        let parts = bs58::inspect_check_with_sha256::<Zero>(&encoded, scheme).unwrap();
+        assert!(parts.checksum_valid);
+        assert_eq!([0; 4], parts.checksum);
+        assert!(
+            !bs58::inspect_check(&encoded, scheme)
+                .unwrap()
+                .checksum_valid
+        );
+
+        let rewritten = bs58::rewrite_check_version_with_sha256::<Zero>(&encoded, 0x6f).unwrap();
+        assert_eq!(
+            vec![0x6f, 2, 3],
+            bs58::decode(&rewritten)
+                .with_sha256::<Zero>()
+                .with_check(None)
+                .into_vec()
+                .unwrap()
+        );
+        assert_matches!(
+            bs58::rewrite_check_version(&encoded, 0x6f),
+            Err(bs58::decode::Error::InvalidChecksum { .. })
+        );
+
+        let results = bs58::verify_check_batch_with_sha256::<Zero, _>(&[&encoded], None);
+        assert_eq!(vec![Ok(input.to_vec())], results);
+        assert_matches!(
+            *bs58::verify_check_batch(&[&encoded], None),
+            [Err(bs58::decode::Error::InvalidChecksum { .. })]
+        );
+    }
+
+    #[cfg(feature = "cb58")]
+    {
+        let encoded = bs58::encode(input)
+            .as_cb58(None)
+            .with_sha256::<Zero>()
+            .into_string();
+        assert_eq!(unchecked, encoded);
+        assert_eq!(
+            input.to_vec(),
+            bs58::decode(&encoded)
+                .as_cb58(None)
+                .with_sha256::<Zero>()
+                .into_vec()
+                .unwrap()
+        );
+        assert_matches!(
+            bs58::decode(&encoded).as_cb58(None).into_vec(),
+            Err(bs58::decode::Error::InvalidChecksum { .. })
+        );
+
+        let scheme = bs58::decode::CheckScheme::CB58;
+        let parts = bs58::inspect_check_with_sha256::<Zero>(&encoded, scheme).unwrap();
+        assert!(parts.checksum_valid);
+    }
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_verify_check_batch() {
+    let inputs: Vec<String> = (0..=u8::MAX)
+        .map(|ver| bs58::encode([ver, 1, 2, 3]).with_check().into_string())
+        .chain(["he11owor1d".to_owned(), "he11owor1d!".to_owned()])
+        .collect();
+
+    let results = bs58::verify_check_batch(&inputs, None);
+    assert_eq!(inputs.len(), results.len());
+    for (ver, result) in (0..=u8::MAX).zip(&results) {
+        assert_eq!(&Ok(vec![ver, 1, 2, 3]), result);
+    }
+    assert_matches!(
+        results[256],
+        Err(bs58::decode::Error::InvalidChecksum { .. })
+    );
+    assert_matches!(
+        results[257],
+        Err(bs58::decode::Error::InvalidCharacter { character: '!', .. })
+    );
+
+    let results = bs58::verify_check_batch(&inputs[..3], Some(1));
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidVersion {
+            ver: 0,
+            expected_ver: 1
+        }),
+        results[0]
+    );
+    assert_eq!(Ok(vec![1, 1, 2, 3]), results[1]);
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidVersion {
+            ver: 2,
+            expected_ver: 1
+        }),
+        results[2]
+    );
+
+    assert!(bs58::verify_check_batch::<&str>(&[], None).is_empty());
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_inspect_check() {
+    use bs58::decode::CheckScheme;
+
+    for &(val, s) in cases::CHECK_TEST_CASES.iter() {
+        let parts = bs58::inspect_check(s, CheckScheme::Check).unwrap();
+        assert!(parts.checksum_valid);
+        assert_eq!(val.first().copied(), parts.version);
+        assert_eq!(val.get(1..).unwrap_or_default(), &*parts.payload);
+
+        let mut decoded = bs58::decode(s).into_vec().unwrap();
+        assert_eq!(&decoded[val.len()..], parts.checksum);
+
+        // Corrupt the checksum, all the parts are still available
+        *decoded.last_mut().unwrap() ^= 1;
+        let corrupted = bs58::encode(&decoded).into_string();
+        let corrupted_parts = bs58::inspect_check(&corrupted, CheckScheme::Check).unwrap();
+        assert!(!corrupted_parts.checksum_valid);
+        assert_eq!(parts.version, corrupted_parts.version);
+        assert_eq!(parts.payload, corrupted_parts.payload);
+        assert_eq!(decoded[val.len()..], corrupted_parts.checksum);
+    }
+
+    assert_eq!(
+        bs58::inspect_check("1", CheckScheme::Check),
+        Err(bs58::decode::Error::NoChecksum)
+    );
+    assert_matches!(
+        bs58::inspect_check("l", CheckScheme::Check),
+        Err(bs58::decode::Error::InvalidCharacter { .. })
+    );
+}
+
+#[test]
+#[cfg(feature = "cb58")]
+fn test_inspect_cb58() {
+    use bs58::decode::CheckScheme;
+
+    for &(val, _) in cases::TEST_CASES.iter() {
+        let s = bs58::encode(val).as_cb58(None).into_string();
+        let parts = bs58::inspect_check(s, CheckScheme::CB58).unwrap();
+        assert!(parts.checksum_valid);
+        assert_eq!(val.first().copied(), parts.version);
+        assert_eq!(val.get(1..).unwrap_or_default(), &*parts.payload);
+    }
+
+    let parts = bs58::inspect_check("PWEu9GGN", CheckScheme::CB58).unwrap();
+    assert!(!parts.checksum_valid);
+}
+
+#[test]
+fn test_decode_auto() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(
+            (val.to_vec(), bs58::Alphabet::BITCOIN),
+            bs58::decode_auto(s).unwrap()
+        );
+    }
+
+    // The alphabet set on the builder is ignored
+    assert_eq!(
+        bs58::decode("he11owor1d")
+            .with_alphabet(bs58::Alphabet::RIPPLE)
+            .into_vec_detecting_alphabet()
+            .unwrap()
+            .1,
+        bs58::Alphabet::BITCOIN
+    );
+
+    assert_eq!(
+        bs58::decode_auto("he11o world").unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_detecting_alphabet() {
+    for alphabet in [
+        bs58::Alphabet::BITCOIN,
+        bs58::Alphabet::RIPPLE,
+        bs58::Alphabet::FLICKR,
+    ] {
+        for &(val, _) in cases::TEST_CASES.iter() {
+            let s = bs58::encode(val)
+                .with_alphabet(alphabet)
+                .with_check()
+                .into_string();
+            let (decoded, detected) = bs58::decode(&s)
+                .with_check(None)
+                .into_vec_detecting_alphabet()
+                .unwrap();
+            assert_eq!(val, &*decoded);
+            assert_eq!(alphabet, detected);
+        }
+    }
+
+    // The genesis account of the XRP ledger
+    let (decoded, detected) = bs58::decode("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")
+        .with_check(Some(0))
+        .into_vec_detecting_alphabet()
+        .unwrap();
+    assert_eq!(21, decoded.len());
+    assert_eq!(bs58::Alphabet::RIPPLE, detected);
+
+    assert_matches!(
+        bs58::decode("he11owor1d")
+            .with_check(None)
+            .into_vec_detecting_alphabet(),
+        Err(bs58::decode::Error::InvalidChecksum { .. })
+    );
+}
+
+#[test]
+fn test_u32_slice() {
+    let inputs: &[&[u32]] = &[
+        &[],
+        &[0],
+        &[0, 0, 1],
+        &[1, 0],
+        &[u32::MAX; 8],
+        &[0x04305e2b, 0x2473f058],
+    ];
+    for &input in inputs {
+        let encoded = bs58::encode_u32_slice(input);
+        let bytes: Vec<u8> = input.iter().flat_map(|n| n.to_be_bytes()).collect();
+        assert_eq!(bs58::encode(&bytes).into_string(), encoded);
+        assert_eq!(input, &*bs58::decode_u32_slice(&encoded).unwrap());
+    }
+
+    for len in [1, 2, 3, 5, 7] {
+        let encoded = bs58::encode(vec![0xff; len]).into_string();
+        assert_eq!(
+            bs58::decode_u32_slice(encoded).unwrap_err(),
+            bs58::decode::Error::UnalignedLength { len, alignment: 4 }
+        );
+    }
+
+    assert_eq!(
+        bs58::decode_u32_slice("he11o world").unwrap_err(),
+        bs58::decode::Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        }
+    );
+}
+
+#[test]
+fn test_decode_lenient() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(
+            (val.to_vec(), true),
+            bs58::decode(s).into_vec_lenient().unwrap()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_lenient() {
+    for &(val, s) in cases::CHECK_TEST_CASES.iter() {
+        assert_eq!(
+            (val.to_vec(), true),
+            bs58::decode(s).with_check(None).into_vec_lenient().unwrap()
+        );
+
+        let mut decoded = bs58::decode(s).into_vec().unwrap();
+        *decoded.last_mut().unwrap() ^= 1;
+        let corrupted = bs58::encode(&decoded).into_string();
+        assert_eq!(
+            (val.to_vec(), false),
+            bs58::decode(&corrupted)
+                .with_check(None)
+                .into_vec_lenient()
+                .unwrap()
+        );
+        // The version isn't checked when the checksum is invalid
+        assert_eq!(
+            (val.to_vec(), false),
+            bs58::decode(&corrupted)
+                .with_check(Some(0xff))
+                .into_vec_lenient()
+                .unwrap()
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("PWEu9GGN")
+            .with_check(Some(0x2e))
+            .into_vec_lenient()
+            .unwrap_err(),
+        bs58::decode::Error::InvalidVersion {
+            ver: 0x2d,
+            expected_ver: 0x2e
+        }
+    );
+    assert_eq!(
+        bs58::decode("1").with_check(None).into_vec_lenient(),
+        Err(bs58::decode::Error::NoChecksum)
+    );
+
+    // Other options apply to the payload from the first decode
+    assert_eq!(
+        (vec![0x2d, 0x31], false),
+        bs58::decode("PWEu9GGP")
+            .with_check(None)
+            .expect_len(2)
+            .into_vec_lenient()
+            .unwrap()
+    );
+    assert_eq!(
+        bs58::decode("PWEu9GGP")
+            .with_check(None)
+            .expect_len(3)
+            .into_vec_lenient(),
+        Err(bs58::decode::Error::WrongLength {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        (bs58::decode("he11owor1e").into_vec().unwrap(), false),
+        bs58::decode("he11owor1e.45W3Aw")
+            .with_check_delimited('.')
+            .into_vec_lenient()
+            .unwrap()
+    );
+    assert_eq!(
+        (bs58::decode("he11owor1d").into_vec().unwrap(), true),
+        bs58::decode("he11owor1d.45W3Aw")
+            .with_check_delimited('.')
+            .into_vec_lenient()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_decode_check_fn_lenient() {
+    let sum = |payload: &[u8], checksum: &mut [u8]| {
+        let sum = payload.iter().map(|&b| u16::from(b)).sum::<u16>();
+        checksum.copy_from_slice(&sum.to_be_bytes());
+    };
+
+    for &(val, _) in cases::TEST_CASES.iter() {
+        let encoded = bs58::encode(val).with_check_fn::<2>(&sum).into_string();
+        assert_eq!(
+            (val.to_vec(), true),
+            bs58::decode(&encoded)
+                .with_check_fn::<2>(&sum)
+                .into_vec_lenient()
+                .unwrap()
+        );
+
+        let mut decoded = bs58::decode(&encoded).into_vec().unwrap();
+        *decoded.last_mut().unwrap() ^= 1;
+        let corrupted = bs58::encode(&decoded).into_string();
+        assert_eq!(
+            (val.to_vec(), false),
+            bs58::decode(&corrupted)
+                .with_check_fn::<2>(&sum)
+                .into_vec_lenient()
+                .unwrap()
+        );
+    }
+
+    assert_eq!(
+        bs58::decode("2")
+            .with_check_fn::<2>(&sum)
+            .into_vec_lenient(),
+        Err(bs58::decode::Error::InvalidCheckFnChecksum)
+    );
+}
+
+#[test]
+fn test_decode_until_delimiter() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let mut input = s.as_bytes().to_vec();
+        input.extend_from_slice(b"\0more\0data");
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode(&input).until_delimiter(0).into_vec().unwrap()
+        );
+        assert_eq!(
+            (val.to_vec(), s.len() + 1),
+            bs58::decode(&input)
+                .until_delimiter(0)
+                .into_vec_prefix()
+                .unwrap()
+        );
+    }
+
+    // Without a delimiter the whole input is used
+    assert_eq!(
+        vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+        bs58::decode("he11owor1d")
+            .until_delimiter(0)
+            .into_vec()
+            .unwrap()
+    );
+
+    // Invalid characters before the delimiter are still errors
+    assert_eq!(
+        bs58::decode("he11o world\0").until_delimiter(0).into_vec(),
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        })
+    );
+}
+
+#[test]
+fn test_decode_crc32_footer() {
+    fn frame(s: &str, crc: u32) -> Vec<u8> {
+        let mut input = s.as_bytes().to_vec();
+        input.push(b'|');
+        input.extend_from_slice(&crc.to_be_bytes());
+        input
+    }
+
+    // CRC-32 check value
+    let input = frame("123456789", 0xcbf43926);
+    assert_eq!(
+        bs58::decode("123456789").into_vec().unwrap(),
+        bs58::decode(&input)
+            .with_crc32_footer(b'|')
+            .into_vec()
+            .unwrap()
+    );
+
+    let mut input = frame("he11owor1d", 0x7adf6dd4);
+    input.extend_from_slice(b"rest");
+    assert_eq!(
+        (vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], 15),
+        bs58::decode(&input)
+            .with_crc32_footer(b'|')
+            .into_vec_prefix()
+            .unwrap()
+    );
+
+    assert_eq!(
+        bs58::decode(frame("he11owor1e", 0x7adf6dd4))
+            .with_crc32_footer(b'|')
+            .into_vec(),
+        Err(bs58::decode::Error::InvalidCrc32 {
+            crc: 0x0dd85d42,
+            expected_crc: 0x7adf6dd4
+        })
+    );
+
+    assert_eq!(
+        bs58::decode(b"he11owor1d|\x7a\xdf")
+            .with_crc32_footer(b'|')
+            .into_vec(),
+        Err(bs58::decode::Error::InputTooShort {
+            expected: 15,
+            actual: 13
+        })
+    );
+    assert_eq!(
+        bs58::decode("he11owor1d")
+            .with_crc32_footer(b'|')
+            .into_vec(),
+        Err(bs58::decode::Error::InputTooShort {
+            expected: 15,
+            actual: 10
+        })
+    );
+}
+
+#[test]
+fn test_decode_expect_len() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let (encoded, len) = bs58::encode(val).into_indexed();
+        assert_eq!((s, val.len()), (&*encoded, len));
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode(&encoded).expect_len(len).into_vec().unwrap()
+        );
+        assert_eq!(
+            bs58::decode(&encoded).expect_len(len + 1).into_vec(),
+            Err(bs58::decode::Error::WrongLength {
+                expected: len + 1,
+                actual: len
+            })
+        );
+    }
+
+    // Invalid input is reported before the length
+    assert_eq!(
+        bs58::decode("he11o world").expect_len(8).into_vec(),
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_expect_len() {
+    for &(val, s) in cases::CHECK_TEST_CASES.iter() {
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode(s)
+                .with_check(None)
+                .expect_len(val.len())
+                .into_vec()
+                .unwrap()
+        );
+        assert_eq!(
+            bs58::decode(s)
+                .with_check(None)
+                .expect_len(val.len() + 4)
+                .into_vec(),
+            Err(bs58::decode::Error::WrongLength {
+                expected: val.len() + 4,
+                actual: val.len()
+            })
+        );
+    }
+}
+
+#[test]
+fn test_decode_chars() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let chars: Vec<char> = s.chars().collect();
+        assert_eq!(val.to_vec(), bs58::decode_chars(&chars).into_vec().unwrap());
+    }
+
+    let chars: Vec<char> = "he11o wörld".chars().collect();
+    assert_eq!(
+        bs58::decode_chars(&chars[..5]).into_vec().unwrap(),
+        bs58::decode("he11o").into_vec().unwrap()
+    );
+    assert_eq!(
+        bs58::decode_chars(&chars).into_vec(),
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        })
+    );
+    assert_eq!(
+        bs58::decode_chars(&chars[6..]).into_vec(),
+        Err(bs58::decode::Error::NonAsciiCharacter { index: 1 })
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_chars_check() {
+    for &(val, s) in cases::CHECK_TEST_CASES.iter() {
+        let chars: Vec<char> = s.chars().collect();
+        assert_eq!(
+            val.to_vec(),
+            bs58::decode_chars(&chars)
+                .with_check(None)
+                .into_vec()
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_is_user_input_error() {
+    use bs58::decode::Error;
+
+    for err in [
+        Error::InvalidCharacter {
+            character: ' ',
+            index: 0,
+        },
+        Error::NonAsciiCharacter { index: 0 },
+        Error::InputTooShort {
+            expected: 1,
+            actual: 0,
+        },
+        Error::MissingPrefix,
+        Error::WrongLength {
+            expected: 1,
+            actual: 0,
+        },
+    ] {
+        assert!(err.is_user_input_error(), "{:?}", err);
+    }
+    assert!(!Error::BufferTooSmall.is_user_input_error());
+
+    assert!(!bs58::encode::Error::BufferTooSmall.is_user_input_error());
+    assert!(bs58::encode::Error::InvalidHex { index: 0 }.is_user_input_error());
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_is_user_input_error_check() {
+    assert!(bs58::decode("he11owor1d")
+        .with_check(None)
+        .into_vec()
+        .unwrap_err()
+        .is_user_input_error());
+    assert!(bs58::decode("1")
+        .with_check(None)
+        .into_vec()
+        .unwrap_err()
+        .is_user_input_error());
+    assert!(!bs58::decode("PWEu9GGN")
+        .with_check_over(..4)
+        .into_vec()
+        .unwrap_err()
+        .is_user_input_error());
+}
+
+#[test]
+fn test_decode_static_alphabet() {
+    use bs58::alphabet::{Bitcoin, Gmp};
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let mut output = [0xFF; 512];
+        assert_eq!(
+            Ok(val.len()),
+            bs58::decode::decode_static_into::<Bitcoin>(s.as_bytes(), &mut output)
+        );
+        assert_eq!(val, &output[..val.len()]);
+
+        let encoded = bs58::encode(val)
+            .with_alphabet(bs58::Alphabet::GMP)
+            .into_string();
+        assert_eq!(
+            Ok(val.len()),
+            bs58::decode::decode_static_into::<Gmp>(encoded.as_bytes(), &mut output)
+        );
+        assert_eq!(val, &output[..val.len()]);
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::BufferTooSmall),
+        bs58::decode::decode_static_into::<Bitcoin>(b"he11owor1d", &mut [0; 7])
+    );
+}
+
+#[test]
+fn test_decode_u64() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        if val.len() <= 8 {
+            let expected = val.iter().fold(0, |acc, &b| (acc << 8) | u64::from(b));
+            assert_eq!(Ok(expected), bs58::decode(s).into_u64());
+            assert_eq!(
+                core::num::NonZeroU64::new(expected).ok_or(bs58::decode::Error::IntegerZero),
+                bs58::decode(s).into_nonzero_u64()
+            );
+        } else {
+            assert_eq!(
+                Err(bs58::decode::Error::IntegerOverflow),
+                bs58::decode(s).into_u64()
+            );
+        }
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: 'l',
+            index: 2,
+        }),
+        bs58::decode("hello").into_u64()
+    );
+
+    // The checksum doesn't count towards the integer's length
+    let xor = |payload: &[u8], checksum: &mut [u8]| {
+        checksum.fill(payload.iter().fold(0, |acc, b| acc ^ b));
+    };
+    fn check<const N: usize>(xor: &dyn Fn(&[u8], &mut [u8])) {
+        let encoded = bs58::encode([0xff; 8])
+            .with_check_fn::<N>(xor)
+            .into_string();
+        assert_eq!(
+            Ok(u64::MAX),
+            bs58::decode(&encoded).with_check_fn::<N>(xor).into_u64()
+        );
+        let encoded = bs58::encode([0xff; 9])
+            .with_check_fn::<N>(xor)
+            .into_string();
+        assert_eq!(
+            Err(bs58::decode::Error::IntegerOverflow),
+            bs58::decode(&encoded).with_check_fn::<N>(xor).into_u64()
+        );
+    }
+    check::<1>(&xor);
+    check::<8>(&xor);
+    check::<64>(&xor);
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_check_u64() {
+    use bs58::int::Endianness;
+
+    let encoded = bs58::encode([1, 2, 3, 4, 5]).with_check().into_string();
+    assert_eq!(
+        Ok(0x0102030405),
+        bs58::decode(&encoded).with_check(None).into_u64()
+    );
+    assert_eq!(
+        Ok(0x0102030405_u64),
+        bs58::decode(&encoded)
+            .with_check(None)
+            .into_int(Endianness::Big)
+    );
+
+    let encoded = bs58::encode([0xff; 8]).with_check().into_string();
+    assert_eq!(
+        Ok(u64::MAX),
+        bs58::decode(&encoded).with_check(None).into_u64()
+    );
+    #[cfg(feature = "cb58")]
+    {
+        let encoded = bs58::encode([0xff; 16]).as_cb58(None).into_string();
+        assert_eq!(
+            Ok(u128::MAX),
+            bs58::decode(&encoded)
+                .as_cb58(None)
+                .into_int(Endianness::Little)
+        );
+    }
+
+    let encoded = bs58::encode([0xff; 9]).with_check().into_string();
+    assert_eq!(
+        Err(bs58::decode::Error::IntegerOverflow),
+        bs58::decode(&encoded).with_check(None).into_u64()
+    );
+}
+
+#[test]
+fn test_decode_sanitize_unicode() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let pasted = format!("\u{feff}\u{200b}{s}\u{2060}\u{200d}\u{200c}");
+        assert_eq!(
+            Ok(val.to_vec()),
+            bs58::decode(&pasted).sanitize_unicode().into_vec()
+        );
+        assert_eq!(
+            Err(bs58::decode::Error::NonAsciiCharacter { index: 0 }),
+            bs58::decode(&pasted).into_vec()
+        );
+    }
+
+    let input = "\u{feff}he11owor1d:rest";
+    let (decoded, consumed) = bs58::decode(input)
+        .sanitize_unicode()
+        .into_vec_prefix()
+        .unwrap();
+    assert_eq!(
+        vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+        decoded
+    );
+    assert_eq!(":rest", &input[consumed..]);
+
+    assert_eq!(
+        (
+            vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+            Some(bs58::decode::Error::InvalidCharacter {
+                character: '!',
+                index: 13
+            })
+        ),
+        bs58::decode("\u{feff}he11owor1d!\u{200b}")
+            .sanitize_unicode()
+            .into_vec_lossy()
+    );
+
+    assert_eq!(
+        Ok(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]),
+        bs58::decode("\u{feff}addr_he11owor1d")
+            .sanitize_unicode()
+            .strip_prefix("addr_")
+            .into_vec()
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 8,
+        }),
+        bs58::decode("\u{200b}addr_0")
+            .sanitize_unicode()
+            .strip_prefix("addr_")
+            .into_vec()
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::NonAsciiCharacter { index: 3 }),
+        bs58::decode("\u{feff}é").sanitize_unicode().into_vec()
+    );
+}
+
+#[test]
+fn test_matches() {
+    let alpha = bs58::Alphabet::DEFAULT;
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert!(bs58::matches(s, val, alpha));
+        assert!(!bs58::matches(format!("1{s}"), val, alpha));
+        assert!(!bs58::matches(format!("{s}0"), val, alpha));
+        if let Some((_, head)) = val.split_last() {
+            assert!(!bs58::matches(s, head, alpha));
+        }
+        let mut longer = val.to_vec();
+        longer.push(0);
+        assert!(!bs58::matches(s, &longer, alpha));
+    }
+
+    let large = [0xFF; 200];
+    let encoded = bs58::encode(large).into_string();
+    assert!(bs58::matches(&encoded, &large, alpha));
+    assert!(!bs58::matches(&encoded, &large[..199], alpha));
+}
+
+#[test]
+fn test_decode_collect_errors() {
+    for &(_, s) in cases::TEST_CASES.iter() {
+        assert_eq!(
+            Vec::<bs58::decode::Error>::new(),
+            bs58::decode(s).collect_errors()
+        );
+    }
+
+    for input in ["he11o0wor1d", "0OIl", "\u{1f600}\u{e9}", "1\u{200b}2"] {
+        let errors = bs58::decode(input).collect_errors();
+        assert_eq!(
+            Some(&bs58::decode(input).into_vec().unwrap_err()),
+            errors.first()
+        );
+        assert_eq!(
+            input
+                .chars()
+                .filter(|c| !c.is_ascii_alphanumeric() || "0OIl".contains(*c))
+                .count(),
+            errors.len()
+        );
+    }
+
+    assert_eq!(
+        vec![bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 1,
+        }],
+        bs58::decode("r0pN")
+            .with_alphabet(bs58::Alphabet::RIPPLE)
+            .collect_errors()
+    );
+
+    // The input is prepared as for decoding, so the first error matches `into_vec`
+    for input in [
+        "bs58:he11o0wor1d",
+        "\u{feff}bs58:0OIl\u{200b}",
+        "\u{200b}bs58:\u{e9}1",
+    ] {
+        let decode = || bs58::decode(input).strip_prefix("bs58:").sanitize_unicode();
+        let errors = decode().collect_errors();
+        assert_eq!(Some(&decode().into_vec().unwrap_err()), errors.first());
+    }
+    assert_eq!(
+        vec![
+            bs58::decode::Error::InvalidCharacter {
+                character: '0',
+                index: 9,
+            },
+            bs58::decode::Error::InvalidCharacter {
+                character: 'l',
+                index: 12,
+            },
+        ],
+        bs58::decode("\u{feff}bs58:1021l\u{200b}")
+            .strip_prefix("bs58:")
+            .sanitize_unicode()
+            .collect_errors()
+    );
+    assert_eq!(
+        vec![bs58::decode::Error::MissingPrefix],
+        bs58::decode("0OIl").strip_prefix("bs58:").collect_errors()
+    );
+}
+
+#[test]
+fn test_decode_exact_boxed_slice() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(Ok(val), bs58::decode(s).into_exact_boxed_slice().as_deref());
+    }
+
+    let large = [0xFF; 200];
+    let encoded = bs58::encode(large).into_string();
+    assert_eq!(
+        Ok(&large[..]),
+        bs58::decode(&encoded).into_exact_boxed_slice().as_deref()
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: encoded.len(),
+        }),
+        bs58::decode(format!("{encoded}0")).into_exact_boxed_slice()
+    );
+}
+
+#[test]
+fn test_decode_vec_limited() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        for limit in [0, 1, val.len(), val.len() + 1] {
+            assert_eq!(
+                Ok(&val[..limit.min(val.len())]),
+                bs58::decode(s).into_vec_limited(limit).as_deref()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_decode_uuid() {
+    for uuid in [0, 1, 57, 58, 1 << 64, u128::MAX >> 1, u128::MAX] {
+        let encoded = bs58::encode_uuid(uuid);
+        assert_eq!(bs58::UUID_ENCODED_LEN, encoded.len());
+        assert_eq!(Ok(uuid), bs58::decode_uuid(&encoded));
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidUuidLength { len: 23 }),
+        bs58::decode_uuid("11111111111111111111111")
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 21,
+        }),
+        bs58::decode_uuid("1111111111111111111110")
+    );
+}
+
+#[test]
+fn test_decode_int() {
+    use bs58::int::Endianness;
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        if val.len() <= 8 {
+            assert_eq!(
+                bs58::decode(s).into_u64(),
+                bs58::decode(s).into_int(Endianness::Big)
+            );
+            let expected = val
+                .iter()
+                .rev()
+                .fold(0, |acc, &b| (acc << 8) | u64::from(b));
+            assert_eq!(Ok(expected), bs58::decode(s).into_int(Endianness::Little));
+        }
+        if val.len() > 4 {
+            assert_eq!(
+                Err(bs58::decode::Error::IntegerOverflow),
+                bs58::decode(s).into_int::<u32>(Endianness::Little)
+            );
+        }
+    }
+
+    for endianness in [Endianness::Big, Endianness::Little] {
+        for n in [0, 1, 57, 58, 0x0100, 0xFF00, u64::MAX >> 8, u64::MAX] {
+            let encoded = bs58::encode_int(n, endianness);
+            assert_eq!(Ok(n), bs58::decode(&encoded).into_int(endianness));
+        }
+        let encoded = bs58::encode_int(u128::MAX - 1, endianness);
+        assert_eq!(
+            Ok(u128::MAX - 1),
+            bs58::decode(&encoded).into_int(endianness)
+        );
+        assert_eq!(
+            Err(bs58::decode::Error::IntegerOverflow),
+            bs58::decode(&encoded).into_int::<u64>(endianness)
+        );
+    }
+
+    assert_eq!(Endianness::Big, Endianness::default());
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_check_delimited() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let encoded = bs58::encode(val).with_check_delimited('.').into_string();
+        let (payload, checksum) = encoded.split_once('.').unwrap();
+        assert_eq!(s, payload);
+        {
+            let (string, raw_checksum) = bs58::encode(val)
+                .with_check_delimited('.')
+                .into_string_and_checksum();
+            assert_eq!(encoded, string);
+            assert_eq!(
+                Ok(raw_checksum.unwrap().to_vec()),
+                bs58::decode(checksum).into_vec()
+            );
+        }
+        assert_eq!(
+            Ok(encoded.len()),
+            bs58::encode(val)
+                .with_check_delimited('.')
+                .check_encoded_len()
+        );
+        assert!(
+            encoded.len()
+                <= bs58::encode(val)
+                    .with_check_delimited('.')
+                    .max_check_encoded_len()
+        );
+
+        // The checksum segment is the Base58Check checksum of the payload on its own
+        let checked = bs58::decode(bs58::encode(val).with_check().into_string())
+            .into_vec()
+            .unwrap();
+        assert_eq!(
+            Ok(checked[val.len()..].to_vec()),
+            bs58::decode(checksum).into_vec()
+        );
+
+        assert_eq!(
+            Ok(val.to_vec()),
+            bs58::decode(&encoded).with_check_delimited('.').into_vec()
+        );
+        assert_eq!(
+            Err(bs58::decode::Error::NoChecksum),
+            bs58::decode(payload).with_check_delimited('.').into_vec()
+        );
+        assert_eq!(
+            Err(bs58::decode::Error::NoChecksum),
+            bs58::decode(format!("{encoded}zzzzzz"))
+                .with_check_delimited('.')
+                .into_vec()
+        );
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 12,
+        }),
+        bs58::decode("he11owor1d.40W")
+            .with_check_delimited('.')
+            .into_vec()
+    );
+    assert_eq!(
+        "he11owor1d:45W3Aw",
+        bs58::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+            .with_check_delimited(':')
+            .into_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_check_version_only() {
+    for &(val, _) in cases::TEST_CASES.iter() {
+        for version in [0x00, 0x05, 0x6f, 0xff] {
+            let encoded = bs58::encode(val).with_check_version(version).into_string();
+            assert_eq!(Ok(version), bs58::decode(&encoded).check_version_only());
+            assert_eq!(
+                Ok(version),
+                bs58::decode(&encoded)
+                    .with_check(Some(version.wrapping_add(1)))
+                    .check_version_only()
+            );
+        }
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::NoChecksum),
+        bs58::decode("").check_version_only()
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 0,
+        }),
+        bs58::decode("0").check_version_only()
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_check_network_version() {
+    for &(val, _) in cases::TEST_CASES.iter() {
+        let encoded = bs58::encode(val)
+            .with_network_version(7, 0x2d)
+            .into_string();
+        assert_eq!(
+            Ok((7, 0x2d, val.to_vec())),
+            bs58::decode(&encoded).into_check_network_version()
+        );
+
+        let mut prefixed = vec![7, 0x2d];
+        prefixed.extend_from_slice(val);
+        assert_eq!(encoded, bs58::encode(prefixed).with_check().into_string());
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::NoNetworkVersion),
+        bs58::decode(bs58::encode([7]).with_check().into_string()).into_check_network_version()
+    );
+    assert_matches!(
+        bs58::decode("6daR2mjseBoDRR4Sg").into_check_network_version(),
+        Err(bs58::decode::Error::InvalidChecksum { .. })
+    );
+}
+
+#[test]
+fn test_transcode() {
+    use bs58::Alphabet;
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let ripple = bs58::encode(val)
+            .with_alphabet(Alphabet::RIPPLE)
+            .into_string();
+        assert_eq!(
+            Ok(&*ripple),
+            bs58::transcode(s, Alphabet::BITCOIN, Alphabet::RIPPLE).as_deref()
+        );
+        assert_eq!(
+            Ok(s),
+            bs58::transcode(&ripple, Alphabet::RIPPLE, Alphabet::BITCOIN).as_deref()
+        );
+
+        let flickr = bs58::transcode(s, Alphabet::BITCOIN, Alphabet::FLICKR).unwrap();
+        let gmp = bs58::transcode(&flickr, Alphabet::FLICKR, Alphabet::GMP).unwrap();
+        assert_eq!(
+            Ok(s),
+            bs58::transcode(&gmp, Alphabet::GMP, Alphabet::BITCOIN).as_deref()
+        );
+    }
+}
+
+#[test]
+fn test_expect_leading_zeros() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let zeros = val.iter().take_while(|&&b| b == 0).count();
+        assert_eq!(
+            Ok(val.to_vec()),
+            bs58::decode(s).expect_leading_zeros(zeros).into_vec()
+        );
+        assert_eq!(
+            Err(bs58::decode::Error::WrongLeadingZeros {
+                expected: zeros + 1,
+                actual: zeros,
+            }),
+            bs58::decode(s).expect_leading_zeros(zeros + 1).into_vec()
+        );
+    }
+
+    assert_eq!(
+        Ok(vec![57]),
+        bs58::decode("11z")
+            .padded()
+            .expect_leading_zeros(0)
+            .into_vec()
+    );
+}
+
+#[test]
+fn test_solana_pubkey() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        match <[u8; 32]>::try_from(val) {
+            Ok(pubkey) => {
+                assert_eq!(Ok(pubkey), bs58::solana::decode_pubkey(s));
+                assert_eq!(s, bs58::solana::encode_pubkey(&pubkey));
+            }
+            Err(_) if val.len() < 32 => assert_eq!(
+                Err(bs58::decode::Error::WrongLength {
+                    expected: 32,
+                    actual: val.len(),
+                }),
+                bs58::solana::decode_pubkey(s)
+            ),
+            Err(_) => assert_eq!(
+                Err(bs58::decode::Error::BufferTooSmall),
+                bs58::solana::decode_pubkey(s)
+            ),
+        }
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 0,
+        }),
+        bs58::solana::decode_pubkey("0okenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+    );
+}
+
+#[test]
+fn test_decode_arc() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(val, &*bs58::decode(s).into_arc().unwrap());
+    }
+
+    let long = [0xAB; 300];
+    let encoded = bs58::encode(long).into_string();
+    assert_eq!(long, *bs58::decode(&encoded).into_arc().unwrap());
+
+    assert_eq!(
+        bs58::decode::Error::InvalidCharacter {
+            character: 'l',
+            index: 2,
+        },
+        bs58::decode("hello").into_arc().unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_max_digits() {
+    // All maximum digits have the longest carry chains, the result is 58^len - 1 so has no
+    // leading zeros and re-encodes to the same string
+    for len in [10, 11, 21, 22, 43, 44, 88, 175, 350] {
+        let encoded = "z".repeat(len);
+        let decoded = bs58::decode(&encoded).into_vec().unwrap();
+        assert_ne!(Some(&0), decoded.first());
+        assert_eq!(encoded, bs58::encode(&decoded).into_string());
+
+        // Adding one carries all the way through, giving 58^len which is `2` followed by zeros
+        let mut one_more = decoded.clone();
+        let mut carry = true;
+        for byte in one_more.iter_mut().rev() {
+            (*byte, carry) = byte.overflowing_add(carry as u8);
+        }
+        if carry {
+            one_more.insert(0, 1);
+        }
+        assert_eq!(
+            one_more,
+            bs58::decode(format!("2{}", "1".repeat(len)))
+                .into_vec()
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_decode_left_aligned() {
+    use bs58::decode::LeftAligned;
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let mut field = [0xFF; 64];
+        if val.len() <= field.len() {
+            assert_eq!(Ok(val.len()), bs58::decode(s).onto(LeftAligned(&mut field)));
+            assert_eq!(val, &field[..val.len()]);
+            assert!(field[val.len()..].iter().all(|&b| b == 0));
+        }
+
+        if !val.is_empty() {
+            let mut field = vec![0xFF; val.len() - 1];
+            assert_eq!(
+                Err(bs58::decode::Error::BufferTooSmall),
+                bs58::decode(s).onto(LeftAligned(&mut field))
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_rewrite_check_version() {
+    // Bitcoin mainnet P2PKH and P2SH addresses and their testnet equivalents
+    for (mainnet, testnet, testnet_ver) in [
+        (
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            "mrS8eVKXguwufwvsVe9GtgGb7fif9UQeAu",
+            0x6f,
+        ),
+        (
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+            "2N9hLwkSqr1cPQAPxbrGVUjxyjD11G2e1he",
+            0xc4,
+        ),
+    ] {
+        assert_eq!(
+            Ok(testnet),
+            bs58::rewrite_check_version(mainnet, testnet_ver).as_deref()
+        );
+        let mainnet_ver = bs58::decode(mainnet).with_check(None).into_vec().unwrap()[0];
+        assert_eq!(
+            Ok(mainnet),
+            bs58::rewrite_check_version(testnet, mainnet_ver).as_deref()
+        );
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::NoChecksum),
+        bs58::rewrite_check_version(bs58::encode([]).with_check().into_string(), 0)
+    );
+}
+
+#[test]
+fn test_decode_canonical_flag() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(
+            Ok((val.to_vec(), true)),
+            bs58::decode(s).into_vec_canonical_flag()
+        );
+        assert_eq!(
+            Ok((val.to_vec(), false)),
+            bs58::decode(format!("x:{}", s))
+                .strip_prefix("x:")
+                .into_vec_canonical_flag()
+        );
+    }
+
+    assert_eq!(
+        Ok((vec![0x61], false)),
+        bs58::decode("12g").padded().into_vec_canonical_flag()
+    );
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_decode_canonical_flag_check() {
+    assert_eq!(
+        Ok((vec![0x2d, 0x31], true)),
+        bs58::decode("PWEu9GGN")
+            .with_check(None)
+            .into_vec_canonical_flag()
+    );
+    assert_matches!(
+        bs58::decode("PWEu9GGP")
+            .with_check(None)
+            .into_vec_canonical_flag(),
+        Err(bs58::decode::Error::InvalidChecksum { .. })
+    );
+}
+
+#[test]
+fn test_decode_all_lengths() {
+    // Covers both the byte at a time and limb decoders, which are chosen by input length
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    for len in 0..400 {
+        let data: Vec<u8> = (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 24) as u8
+            })
+            .collect();
+        let encoded = bs58::encode(&data).into_string();
+        assert_eq!(data, bs58::decode(&encoded).into_vec().unwrap(), "{}", len);
+
+        let mut output = vec![0; len];
+        assert_eq!(Ok(len), bs58::decode(&encoded).onto(&mut output[..]));
+        assert_eq!(data, output);
+        if let Some(short) = len.checked_sub(1) {
+            assert_eq!(
+                Err(bs58::decode::Error::BufferTooSmall),
+                bs58::decode(&encoded).onto(&mut output[..short])
+            );
+        }
+    }
+}
+
+#[test]
+fn test_decode_error_precedence() {
+    // Invalid characters are reported even when the buffer would overflow before reaching them
+    // 58^300 - 1 is 220 bytes long
+    let input = format!("{}0", "z".repeat(300));
+    let mut output = [0; 256];
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 300,
+        }),
+        bs58::decode(&input).onto(&mut output[..220])
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 300,
+        }),
+        bs58::decode(&input).onto(&mut output[..219])
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCharacter {
+            character: '!',
+            index: 1,
+        }),
+        bs58::decode(format!("z{}", "!".repeat(15))).onto(&mut output[..4])
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::BufferTooSmall),
+        bs58::decode("z".repeat(300)).onto(&mut output[..219])
+    );
+    assert_eq!(
+        Err(bs58::decode::Error::NonAsciiCharacter { index: 12 }),
+        bs58::decode("111zzzzzzzzz\u{e9}zz").onto(&mut output[..])
+    );
+}
+
+#[test]
+fn test_check_fn() {
+    // Big-endian sum of the payload bytes
+    let sum = |payload: &[u8], checksum: &mut [u8]| {
+        let sum: u16 = payload.iter().map(|&b| u16::from(b)).sum();
+        checksum.copy_from_slice(&sum.to_be_bytes());
+    };
+
+    for &(val, _) in cases::TEST_CASES.iter() {
+        let encoded = bs58::encode(val).with_check_fn::<2>(&sum).into_string();
+
+        let mut raw = val.to_vec();
+        let total: u16 = val.iter().map(|&b| u16::from(b)).sum();
+        raw.extend_from_slice(&total.to_be_bytes());
+        assert_eq!(bs58::encode(&raw).into_string(), encoded);
+
+        assert_eq!(
+            Ok(val.to_vec()),
+            bs58::decode(&encoded).with_check_fn::<2>(&sum).into_vec()
+        );
+
+        raw[0] ^= 1;
+        assert_eq!(
+            Err(bs58::decode::Error::InvalidCheckFnChecksum),
+            bs58::decode(bs58::encode(&raw).into_string())
+                .with_check_fn::<2>(&sum)
+                .into_vec()
+        );
+    }
+
+    assert_eq!(
+        Err(bs58::decode::Error::InvalidCheckFnChecksum),
+        bs58::decode("2").with_check_fn::<2>(&sum).into_vec()
+    );
+}
+
+#[test]
+#[cfg(any(feature = "check", feature = "cb58"))]
+fn test_verify_external_checksum() {
+    use bs58::decode::CheckScheme;
+
+    let schemes = [
+        #[cfg(feature = "check")]
+        CheckScheme::Check,
+        #[cfg(feature = "cb58")]
+        CheckScheme::CB58,
+    ];
+    for scheme in schemes {
+        for &(val, s) in cases::TEST_CASES.iter() {
+            let embedded = match scheme {
+                #[cfg(feature = "check")]
+                CheckScheme::Check => bs58::encode(val).with_check().into_vec(),
+                #[cfg(feature = "cb58")]
+                CheckScheme::CB58 => bs58::encode(val).as_cb58(None).into_vec(),
+                _ => unreachable!(),
+            };
+            let raw = bs58::decode(embedded).into_vec().unwrap();
+            let checksum: [u8; 4] = raw[raw.len() - 4..].try_into().unwrap();
+
+            assert_eq!(
+                Ok(val.to_vec()),
+                bs58::decode(s).verify_external_checksum(checksum, scheme)
+            );
+
+            let mut wrong = checksum;
+            wrong[3] ^= 1;
+            assert_matches!(
+                bs58::decode(s).verify_external_checksum(wrong, scheme),
+                Err(bs58::decode::Error::InvalidChecksum { checksum, .. }) if checksum == wrong
+            );
+        }
+    }
+}
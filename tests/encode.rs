@@ -16,6 +16,15 @@ fn test_encode() {
             assert_eq!(&FILLER[s.len()..], &bytes[s.len()..]);
         }
 
+        {
+            let mut bytes = FILLER;
+            assert_eq!(
+                Ok(s.as_bytes()),
+                bs58::encode(val).into_slice(&mut bytes[..])
+            );
+            assert_eq!(&FILLER[s.len()..], &bytes[s.len()..]);
+        }
+
         {
             let mut bytes = FILLER;
             if !s.is_empty() {
@@ -30,6 +39,19 @@ fn test_encode() {
             assert_eq!(&FILLER[(s.len() + 1)..], &bytes[(s.len() + 1)..]);
         }
 
+        {
+            let array = bs58::encode(val).into_array_const_unwrap::<256>();
+            let mut check = [0; 256];
+            check[..s.len()].copy_from_slice(s.as_bytes());
+            assert_eq!(array, check);
+        }
+
+        {
+            let mut string = "hello ".to_owned();
+            bs58::write_base58!(&mut string, val, 256).unwrap();
+            assert_eq!(("hello ", s), string.split_at(6));
+        }
+
         const PREFIX: &[u8] = &[0, 1, 2];
 
         {
@@ -80,6 +102,46 @@ fn test_encode() {
     }
 }
 
+#[test]
+#[cfg(feature = "check")]
+fn test_check_encoded_len() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(Ok(s.len()), bs58::encode(val).check_encoded_len());
+        assert!(s.len() <= bs58::encode(val).max_check_encoded_len());
+    }
+
+    // Values around the limit of the stack buffer, with leading zeros not counting towards it
+    for len in [1, 63, 64, 65, 1020] {
+        for byte in [0x01, 0xff] {
+            let mut input = vec![0; 3];
+            input.resize(3 + len, byte);
+            let encoded = bs58::encode(&input).with_check().into_string();
+            assert_eq!(
+                Ok(encoded.len()),
+                bs58::encode(&input).with_check().check_encoded_len()
+            );
+        }
+    }
+    assert_eq!(
+        Err(bs58::encode::Error::BufferTooSmall),
+        bs58::encode([0xff; 1021]).with_check().check_encoded_len()
+    );
+
+    let sum = |payload: &[u8], checksum: &mut [u8]| {
+        checksum[0] = payload.iter().fold(0, |sum, byte| sum ^ byte);
+    };
+    let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    assert_eq!(
+        Ok(bs58::encode(input)
+            .with_check_fn::<1>(&sum)
+            .into_string()
+            .len()),
+        bs58::encode(input)
+            .with_check_fn::<1>(&sum)
+            .check_encoded_len()
+    );
+}
+
 #[test]
 #[cfg(feature = "check")]
 fn test_encode_check() {
@@ -88,6 +150,46 @@ fn test_encode_check() {
 
         assert_eq!(s.as_bytes(), &*bs58::encode(val).with_check().into_vec());
 
+        assert_eq!(
+            Ok(s.len()),
+            bs58::encode(val).with_check().check_encoded_len()
+        );
+        assert_eq!(
+            Ok(s.len() + 3),
+            bs58::encode(val)
+                .with_check()
+                .padded_to(s.len() + 3)
+                .check_encoded_len()
+        );
+        if !s.is_empty() {
+            assert_eq!(
+                Err(bs58::encode::Error::BufferTooSmall),
+                bs58::encode(val)
+                    .with_check()
+                    .padded_to(s.len() - 1)
+                    .check_encoded_len()
+            );
+        }
+        {
+            let max_len = bs58::encode(val).with_check().max_check_encoded_len();
+            assert!((s.len()..=s.len() + 2).contains(&max_len));
+        }
+
+        {
+            let (string, checksum) = bs58::encode(val).with_check().into_string_and_checksum();
+            assert_eq!(s, string);
+            let decoded = bs58::decode(s).into_vec().unwrap();
+            assert_eq!(&decoded[val.len()..], checksum.unwrap());
+        }
+        if !val.is_empty() {
+            assert_eq!(
+                Ok(s.len()),
+                bs58::encode(&val[1..])
+                    .with_check_version(val[0])
+                    .check_encoded_len()
+            );
+        }
+
         {
             let mut bytes = FILLER;
             assert_eq!(
@@ -125,6 +227,134 @@ fn test_encode_check() {
     }
 }
 
+#[test]
+fn test_encode_vec_is_ascii() {
+    let alpha = bs58::Alphabet::new(b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXY")
+        .unwrap();
+    for &(val, _) in cases::TEST_CASES.iter() {
+        for alpha in [bs58::Alphabet::DEFAULT, &alpha] {
+            let mut vec = b"hello ".to_vec();
+            bs58::encode(val)
+                .with_alphabet(alpha)
+                .onto(&mut vec)
+                .unwrap();
+            assert!(vec.is_ascii());
+        }
+    }
+}
+
+/// Verify that encoding onto a `&mut str` clears every remaining byte of a partially overwritten
+/// multi-byte character, and nothing after it.
+#[test]
+fn test_encode_str_multi_byte_boundary() {
+    let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    let encoded = "he11owor1d";
+    for c in ["®", "€", "😀"] {
+        for overlap in 1..c.len() {
+            let start = encoded.len() - overlap;
+            let mut output = "~".repeat(start);
+            output.push_str(c);
+            output.push_str(c);
+
+            assert_eq!(
+                Ok(encoded.len()),
+                bs58::encode(input).onto(output.as_mut_str())
+            );
+
+            let mut expected = encoded.to_owned();
+            expected.push_str(&"\0".repeat(c.len() - overlap));
+            expected.push_str(c);
+            assert_eq!(expected, output);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_encode_check_version_bytes() {
+    let (version, payload, s) = cases::BIP32_XPUB;
+    assert_eq!(
+        s,
+        bs58::encode(payload)
+            .with_check_version_bytes(version)
+            .into_string()
+    );
+
+    for &(val, s) in cases::CHECK_TEST_CASES[1..].iter() {
+        assert_eq!(
+            s,
+            bs58::encode(&val[1..])
+                .with_check_version_bytes(&val[..1])
+                .into_string()
+        );
+        assert_eq!(
+            s,
+            bs58::encode(&[])
+                .with_check_version_bytes(val)
+                .into_string()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_encode_check_trailing_version() {
+    let (payload, version, s) = cases::CHECK_TRAILING_VERSION;
+    let mut input = payload.to_vec();
+    input.push(version);
+    assert_eq!(s, bs58::encode(input).with_check().into_string());
+}
+
+#[test]
+fn test_encode_gmp() {
+    // Matches `mpz_get_str(NULL, 58, n)`, plus a leading zero digit per leading zero byte
+    let alpha = bs58::Alphabet::GMP;
+    assert_eq!(Ok(()), alpha.self_test());
+    for (input, expected) in [
+        (&b"\x01\x00"[..], "4O"),
+        (b"hello world", "PpS0CJ5BsQnuIuS"),
+        (b"\x00\x00\xff", "004N"),
+    ] {
+        let encoded = bs58::encode(input).with_alphabet(alpha).into_string();
+        assert_eq!(expected, encoded);
+        assert_eq!(
+            input,
+            &*bs58::decode(&encoded)
+                .with_alphabet(alpha)
+                .into_vec()
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_reencode() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let twice = bs58::encode(val).reencode().into_string();
+        assert_eq!(bs58::encode(s).into_string(), twice);
+
+        let once = bs58::decode(twice).into_vec().unwrap();
+        assert_eq!(s.as_bytes(), &*once);
+        assert_eq!(val, &*bs58::decode(once).into_vec().unwrap());
+    }
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_encode_checksum_without_check() {
+    assert_eq!(
+        ("2g".to_owned(), None),
+        bs58::encode([0x61]).into_string_and_checksum()
+    );
+    let sum = |payload: &[u8], checksum: &mut [u8]| checksum[0] = payload[0];
+    assert_eq!(
+        ("8Qp".to_owned(), None),
+        bs58::encode([0x61])
+            .with_check_fn::<1>(&sum)
+            .into_string_and_checksum()
+    );
+}
+
 #[test]
 fn append() {
     let mut buf = "hello world".to_string();
@@ -132,6 +362,43 @@ fn append() {
     assert_eq!("hello world2b", buf.as_str());
 }
 
+#[test]
+fn test_encode_const_exact() {
+    const HELLO: [u8; 10] =
+        bs58::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58].as_slice())
+            .into_array_const_exact();
+    const EMPTY: [u8; 0] = bs58::encode(b"".as_slice()).into_array_const_exact();
+    assert_eq!(b"he11owor1d", &HELLO);
+    assert_eq!(b"", &EMPTY);
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        if s.len() == 7 {
+            assert_eq!(
+                s.as_bytes(),
+                bs58::encode(val).into_array_const_exact::<7>()
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "shorter")]
+fn test_encode_const_exact_too_long_array_panic() {
+    bs58::encode(&b"\x61\x62\x63"[..]).into_array_const_exact::<5>();
+}
+
+#[test]
+#[should_panic(expected = "longer")]
+fn test_encode_const_exact_too_short_array_panic() {
+    bs58::encode(&b"\x61\x62\x63"[..]).into_array_const_exact::<3>();
+}
+
+#[test]
+#[should_panic]
+fn test_encode_const_small_buffer_panic() {
+    bs58::encode(&b"\x61\x62\x63"[..]).into_array_const_unwrap::<3>();
+}
+
 /// Verify that encode_into doesn’t try to write over provided buffer.
 #[test]
 fn test_buffer_too_small() {
@@ -181,3 +448,226 @@ fn encode_stress_test() {
             .into_string();
     }
 }
+
+#[test]
+fn test_encoded_array_len() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert!(s.len() <= bs58::encoded_array_len(val.len()));
+    }
+
+    const INPUT: [u8; 32] = [0xff; 32];
+    const OUTPUT: [u8; bs58::encoded_array_len(INPUT.len())] =
+        bs58::encode(INPUT.as_slice()).into_array_const_unwrap();
+    assert_eq!(
+        bs58::encode(INPUT).into_string().as_bytes(),
+        OUTPUT.split(|&b| b == 0).next().unwrap()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_encoded_array_len_overflow() {
+    bs58::encoded_array_len(usize::MAX / 3 * 2 + 1);
+}
+
+#[test]
+fn test_encode_concat() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(s, bs58::encode_concat(&[val]));
+        for split in 0..=val.len() {
+            let (head, tail) = val.split_at(split);
+            assert_eq!(s, bs58::encode_concat(&[head, tail]));
+            assert_eq!(s, bs58::encode_concat(&[&[], head, &[], tail]));
+        }
+    }
+
+    assert_eq!("", bs58::encode_concat(&[]));
+}
+
+#[test]
+fn test_char_len_range() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let (min, max) = bs58::char_len_range(val.len());
+        assert!((min..=max).contains(&s.len()));
+    }
+
+    for len in 0..=600 {
+        assert_eq!(
+            (
+                bs58::encode(vec![0; len]).into_string().len(),
+                bs58::encode(vec![0xFF; len]).into_string().len()
+            ),
+            bs58::char_len_range(len)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_char_len_range_overflow() {
+    bs58::char_len_range(usize::MAX);
+}
+
+#[test]
+fn test_encode_into_str() {
+    let mut output = String::new();
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(Ok(s), bs58::encode(val).into_str(&mut output));
+    }
+    assert_eq!(
+        Err(bs58::encode::Error::BufferTooSmall),
+        bs58::encode([0xff; 8]).padded_to(4).into_str(&mut output)
+    );
+}
+
+#[test]
+fn test_encode_overwrite() {
+    let mut string = "goodbye".to_owned();
+    let mut vec = b"goodbye".to_vec();
+    let mut slice = [b'a'; 16];
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(s.len(), bs58::encode(val).overwrite(&mut string).unwrap());
+        assert_eq!(s, string);
+
+        assert_eq!(s.len(), bs58::encode(val).overwrite(&mut vec).unwrap());
+        assert_eq!(s.as_bytes(), vec);
+
+        if s.len() <= slice.len() {
+            let len = bs58::encode(val).overwrite(&mut slice[..]).unwrap();
+            assert_eq!(s.as_bytes(), &slice[..len]);
+        }
+    }
+}
+
+#[test]
+fn test_encode_padded() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        for width in [s.len(), s.len() + 1, 20] {
+            if width < s.len() {
+                continue;
+            }
+            let padded = bs58::encode(val).padded_to(width).into_string();
+            assert_eq!(width, padded.len());
+            assert_eq!(format!("{:1>width$}", s), padded);
+
+            let mut output = vec![0; width];
+            let len = bs58::encode(val)
+                .padded_to(width)
+                .onto(&mut output[..])
+                .unwrap();
+            assert_eq!(padded.as_bytes(), &output[..len]);
+        }
+
+        if !s.is_empty() {
+            assert_eq!(
+                bs58::encode(val)
+                    .padded_to(s.len() - 1)
+                    .onto(&mut Vec::new())
+                    .unwrap_err(),
+                bs58::encode::Error::BufferTooSmall
+            );
+        }
+    }
+
+    assert_eq!(
+        bs58::encode([0x61])
+            .padded_to(4)
+            .onto(&mut [0; 3][..])
+            .unwrap_err(),
+        bs58::encode::Error::BufferTooSmall
+    );
+}
+
+#[test]
+fn test_encode_padded_sorts() {
+    let mut values: Vec<u32> = vec![0, 1, 57, 58, 59, 1000, 0xffff, 0x1000000, u32::MAX];
+    let mut encoded: Vec<String> = values
+        .iter()
+        .map(|v| bs58::encode(v.to_be_bytes()).padded_to(6).into_string())
+        .collect();
+    values.sort();
+    encoded.sort();
+    let decoded: Vec<u32> = encoded
+        .iter()
+        .map(|s| {
+            let bytes = bs58::decode(s).into_vec().unwrap();
+            u32::from_be_bytes(bytes[bytes.len() - 4..].try_into().unwrap())
+        })
+        .collect();
+    assert_eq!(values, decoded);
+}
+
+#[test]
+fn test_encode_into_char_iter() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert!(bs58::encode(val).into_char_iter().eq(s.chars()));
+    }
+}
+
+#[test]
+fn test_encode_into_truncated_string() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        for len in [0, 1, 6, s.len(), s.len() + 1] {
+            assert_eq!(
+                &s[..len.min(s.len())],
+                bs58::encode(val).into_truncated_string(len)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_encode_hex() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let hex: String = val.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(s, bs58::encode_hex(&hex).unwrap());
+        assert_eq!(s, bs58::encode_hex(&hex.to_uppercase()).unwrap());
+    }
+
+    for (input, index) in [
+        ("0", 1),
+        ("g0", 0),
+        ("0g", 1),
+        ("00 0", 2),
+        ("000", 3),
+        ("é0", 0),
+    ] {
+        assert_eq!(
+            bs58::encode_hex(input).unwrap_err(),
+            bs58::encode::Error::InvalidHex { index }
+        );
+    }
+}
+
+#[test]
+fn test_encode_static_alphabet() {
+    use bs58::alphabet::{Bitcoin, Flickr, Ripple};
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let mut bytes = FILLER;
+        assert_eq!(
+            Ok(s.len()),
+            bs58::encode::encode_static_into::<Bitcoin>(val, &mut bytes)
+        );
+        assert_eq!(s.as_bytes(), &bytes[..s.len()]);
+        assert_eq!(&FILLER[s.len()..], &bytes[s.len()..]);
+
+        let len = bs58::encode::encode_static_into::<Ripple>(val, &mut bytes).unwrap();
+        assert_eq!(
+            bs58::encode(val)
+                .with_alphabet(bs58::Alphabet::RIPPLE)
+                .into_string()
+                .as_bytes(),
+            &bytes[..len]
+        );
+
+        let len = bs58::encode::encode_static_into::<Flickr>(val, &mut bytes).unwrap();
+        assert_eq!(
+            bs58::encode(val)
+                .with_alphabet(bs58::Alphabet::FLICKR)
+                .into_string()
+                .as_bytes(),
+            &bytes[..len]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Key<const N: usize> {
+    #[serde(with = "bs58::serde_fixed")]
+    key: [u8; N],
+}
+
+#[test]
+fn test_serde_fixed() {
+    let key = Key {
+        key: [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    };
+    let json = r#"{"key":"he11owor1d"}"#;
+    assert_eq!(json, serde_json::to_string(&key).unwrap());
+    assert_eq!(key, serde_json::from_str(json).unwrap());
+
+    let key = Key { key: [0xff; 32] };
+    let json = r#"{"key":"JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFG"}"#;
+    assert_eq!(json, serde_json::to_string(&key).unwrap());
+    assert_eq!(key, serde_json::from_str(json).unwrap());
+}
+
+#[test]
+fn test_serde_fixed_wrong_length() {
+    for input in ["", "he11o", "zzzzzzzzzzzz", "1he11owor1d"] {
+        let json = format!(r#"{{"key":"{}"}}"#, input);
+        let err = serde_json::from_str::<Key<8>>(&json).unwrap_err();
+        assert!(
+            err.to_string().contains("a base58 string encoding 8 bytes"),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn test_serde_fixed_invalid() {
+    let err = serde_json::from_str::<Key<8>>(r#"{"key":"he11o0"}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("provided string contained invalid character '0' at byte 5"),
+        "{}",
+        err
+    );
+}